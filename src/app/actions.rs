@@ -1,6 +1,9 @@
 use std::path::PathBuf;
 
-use super::state::{ModalType, TabIdx};
+use super::{
+    search_filter::SearchMode,
+    state::{ModalType, TabIdx},
+};
 
 #[derive(Clone, Debug)]
 pub enum FileManagerActions {
@@ -11,6 +14,7 @@ pub enum FileManagerActions {
     Panel(PanelAction),
     Tab(TabAction),
     Search(SearchAction),
+    Bookmark(BookmarkAction),
 }
 
 #[derive(Clone, Debug)]
@@ -27,6 +31,7 @@ pub enum SearchAction {
         tab: TabIdx,
         panel_side: PanelSide,
         phrase: String,
+        mode: SearchMode,
     },
     ApplySearch {
         tab: TabIdx,
@@ -34,14 +39,28 @@ pub enum SearchAction {
     },
 }
 
+#[derive(Clone, Debug)]
+pub enum BookmarkAction {
+    Add { key: char, path: PathBuf },
+    Delete { key: char },
+}
+
 #[derive(Clone, Debug)]
 pub enum AppAction {
     Exit,
+    ExitWithoutChangingDir,
     ChildProgramClosed,
     FocusLeft,
     FocusRight,
     ShowModal(ModalType),
     CloseModal,
+    Choose(Vec<PathBuf>),
+    TogglePreview,
+    ToggleMillerColumns,
+    ToggleZoom,
+    CycleSplitDirection,
+    // Positive value grows focused panel, negative shrinks it (in percents)
+    ResizePanels(i16),
 }
 
 #[derive(Clone, Debug)]
@@ -62,6 +81,36 @@ pub enum TabAction {
         panel_side: PanelSide,
         path: PathBuf,
     },
+    HistoryBack {
+        panel_side: PanelSide,
+        tab: TabIdx,
+        steps: usize,
+    },
+    HistoryForward {
+        panel_side: PanelSide,
+        tab: TabIdx,
+        steps: usize,
+    },
+    SelectPath {
+        panel_side: PanelSide,
+        tab: TabIdx,
+        path: PathBuf,
+    },
+    ShowSearchResults {
+        panel_side: PanelSide,
+        root: PathBuf,
+        name: String,
+        paths: Vec<PathBuf>,
+    },
+    ToggleExpanded {
+        panel_side: PanelSide,
+        tab: TabIdx,
+        path: PathBuf,
+    },
+    ToggleFlat {
+        panel_side: PanelSide,
+        tab: TabIdx,
+    },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -90,6 +139,7 @@ pub enum FileAction {
     Copy { from: PanelInfo, to: PanelInfo },
     Move { from: PanelInfo, to: PanelInfo },
     Open { panel: PanelInfo },
+    OpenAtLine { panel: PanelInfo, line: usize },
     Create { file_name: String, panel: PanelInfo },
 }
 
@@ -0,0 +1,112 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use toml::{map::Map, Value};
+
+use super::{
+    config::bookmarks_cfg::parse_bookmarks, file_system::functions::expand_if_contains_tilde,
+};
+
+pub const BOOKMARKS_FILE_NAME: &str = "sfm_bookmarks.toml";
+
+#[derive(Clone, Debug)]
+pub struct Bookmark {
+    pub key: char,
+    pub path: PathBuf,
+    pub is_static: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Bookmarks {
+    static_bookmarks: BTreeMap<char, PathBuf>,
+    bookmarks: BTreeMap<char, PathBuf>,
+    file_path: Option<PathBuf>,
+}
+
+impl Bookmarks {
+    pub fn load(file_path: Option<PathBuf>, static_bookmarks: BTreeMap<char, PathBuf>) -> Self {
+        let bookmarks = match file_path {
+            Some(ref file_path) => match fs::read_to_string(file_path) {
+                Ok(content) => match content.parse::<Value>() {
+                    Ok(bookmarks) => parse_bookmarks(&bookmarks),
+                    Err(_) => BTreeMap::new(),
+                },
+                Err(_) => BTreeMap::new(),
+            },
+            None => BTreeMap::new(),
+        };
+
+        Bookmarks {
+            static_bookmarks,
+            bookmarks,
+            file_path,
+        }
+    }
+
+    pub fn get(&self, key: char) -> Option<PathBuf> {
+        self.bookmarks
+            .get(&key)
+            .or(self.static_bookmarks.get(&key))
+            .cloned()
+    }
+
+    pub fn list(&self) -> Vec<Bookmark> {
+        let mut result = Vec::new();
+        for (key, path) in self.static_bookmarks.iter() {
+            if !self.bookmarks.contains_key(key) {
+                result.push(Bookmark {
+                    key: *key,
+                    path: path.clone(),
+                    is_static: true,
+                });
+            }
+        }
+        for (key, path) in self.bookmarks.iter() {
+            result.push(Bookmark {
+                key: *key,
+                path: path.clone(),
+                is_static: false,
+            });
+        }
+        result.sort_by_key(|bookmark| bookmark.key);
+
+        result
+    }
+
+    pub fn set(&mut self, key: char, path: PathBuf) -> io::Result<()> {
+        let path = expand_if_contains_tilde(&path).unwrap_or(path);
+        self.bookmarks.insert(key, path);
+        self.save()
+    }
+
+    pub fn remove(&mut self, key: char) -> io::Result<()> {
+        self.bookmarks.remove(&key);
+        self.save()
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let file_path = match self.file_path {
+            Some(ref file_path) => file_path,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "can't find config directory",
+                ))
+            }
+        };
+
+        let mut bookmarks = Map::new();
+        for (key, path) in self.bookmarks.iter() {
+            bookmarks.insert(
+                key.to_string(),
+                Value::String(path.to_str().unwrap_or("").to_string()),
+            );
+        }
+        let mut content = Map::new();
+        content.insert("bookmarks".to_string(), Value::Table(bookmarks));
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(file_path, Value::Table(content).to_string())
+    }
+}
@@ -0,0 +1,197 @@
+use std::path::PathBuf;
+
+use super::state::PickerMode;
+
+pub const USAGE: &str = "Usage: sfm [OPTIONS] [LEFT_PATH] [RIGHT_PATH]
+
+Options:
+  -l, --left <PATH>      Open PATH as tab in left panel (can be repeated)
+  -r, --right <PATH>     Open PATH as tab in right panel (can be repeated)
+  -c, --config <FILE>    Use FILE instead of ~/sfm.toml and ~/.config/sfm.toml
+      --read-only        Refuse every modification of files and directories
+      --no-icons         Do not display icons
+      --choose-files <OUT>
+                         Pick files, enter (or open on a file) writes selected
+                         paths to OUT and exits, - means stdout
+      --choose-dir <OUT> Pick directory, enter writes selected directory (or
+                         current one) to OUT and exits, - means stdout
+      --print0           Separate chosen paths with NUL instead of newline
+      --cwd-file <FILE>  Write directory of focused tab to FILE on quit
+  -s, --session <NAME>   Restore and save tabs in session NAME (default: default)
+      --no-session       Do not restore nor save session
+      --import-z <FILE>  Import directories from z/zoxide data file FILE and exit
+      --import-zoxide    Import directories from installed zoxide and exit
+  -h, --help             Print this help and exit
+  -V, --version          Print version and exit";
+
+#[derive(Clone, Debug, Default)]
+pub struct CommandLineArgs {
+    pub left_paths: Vec<PathBuf>,
+    pub right_paths: Vec<PathBuf>,
+    pub config_path: Option<PathBuf>,
+    pub read_only: bool,
+    pub no_icons: bool,
+    pub picker: Option<(PickerMode, PathBuf)>,
+    pub print0: bool,
+    pub cwd_file: Option<PathBuf>,
+    pub session_name: Option<String>,
+    pub no_session: bool,
+    pub import_z: Option<PathBuf>,
+    pub import_zoxide: bool,
+    pub show_help: bool,
+    pub show_version: bool,
+}
+
+impl CommandLineArgs {
+    pub fn parse<TArgs: Iterator<Item = String>>(args: TArgs) -> Result<Self, String> {
+        let mut result = CommandLineArgs::default();
+        let mut positional = Vec::new();
+        let mut args = args.skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-l" | "--left" => result.left_paths.push(next_value(&arg, args.next())?),
+                "-r" | "--right" => result.right_paths.push(next_value(&arg, args.next())?),
+                "-c" | "--config" => result.config_path = Some(next_value(&arg, args.next())?),
+                "--read-only" => result.read_only = true,
+                "--no-icons" => result.no_icons = true,
+                "--choose-files" | "--choose-dir" => {
+                    if result.picker.is_some() {
+                        return Err(
+                            "options '--choose-files' and '--choose-dir' can't be used together"
+                                .to_string(),
+                        );
+                    }
+                    let mode = if arg == "--choose-files" {
+                        PickerMode::Files
+                    } else {
+                        PickerMode::Directory
+                    };
+                    result.picker = Some((mode, next_value(&arg, args.next())?))
+                }
+                "--print0" => result.print0 = true,
+                "--cwd-file" => result.cwd_file = Some(next_value(&arg, args.next())?),
+                "-s" | "--session" => {
+                    let session_name = next_value(&arg, args.next())?;
+                    if session_name.file_name() != Some(session_name.as_os_str()) {
+                        return Err(format!(
+                            "invalid session name '{}'",
+                            session_name.to_str().unwrap_or("")
+                        ));
+                    }
+                    result.session_name = session_name.to_str().map(String::from);
+                }
+                "--no-session" => result.no_session = true,
+                "--import-z" => result.import_z = Some(next_value(&arg, args.next())?),
+                "--import-zoxide" => result.import_zoxide = true,
+                "-h" | "--help" => result.show_help = true,
+                "-V" | "--version" => result.show_version = true,
+                "--" => positional.extend(args.by_ref().map(PathBuf::from)),
+                option if option.starts_with('-') && option.len() > 1 => {
+                    return Err(format!("unknown option '{}'", option))
+                }
+                path => positional.push(PathBuf::from(path)),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        if let Some(left) = positional.next() {
+            result.left_paths.insert(0, left);
+        }
+        if let Some(right) = positional.next() {
+            result.right_paths.insert(0, right);
+        }
+        if let Some(unexpected) = positional.next() {
+            return Err(format!(
+                "unexpected argument '{}'",
+                unexpected.to_str().unwrap_or("")
+            ));
+        }
+
+        if result.session_name.is_some() && result.no_session {
+            return Err(
+                "options '--session' and '--no-session' can't be used together".to_string(),
+            );
+        }
+        if result.print0 && result.picker.is_none() {
+            return Err(
+                "option '--print0' requires '--choose-files' or '--choose-dir'".to_string(),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+fn next_value(option: &str, value: Option<String>) -> Result<PathBuf, String> {
+    match value {
+        Some(value) => Ok(PathBuf::from(value)),
+        None => Err(format!("option '{}' requires a value", option)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CommandLineArgs, String> {
+        CommandLineArgs::parse(
+            std::iter::once("sfm")
+                .chain(args.iter().copied())
+                .map(String::from),
+        )
+    }
+
+    #[test]
+    fn positional_paths_open_first_tabs() {
+        let args = parse(&["/a", "-l", "/c", "/b", "--right", "/d"]).unwrap();
+        assert_eq!(
+            args.left_paths,
+            vec![PathBuf::from("/a"), PathBuf::from("/c")]
+        );
+        assert_eq!(
+            args.right_paths,
+            vec![PathBuf::from("/b"), PathBuf::from("/d")]
+        );
+
+        let args = parse(&["--", "-dir"]).unwrap();
+        assert_eq!(args.left_paths, vec![PathBuf::from("-dir")]);
+    }
+
+    #[test]
+    fn picker_options_set_mode_and_output() {
+        let args = parse(&["--choose-files", "-", "--print0"]).unwrap();
+        assert_eq!(args.picker, Some((PickerMode::Files, PathBuf::from("-"))));
+        assert!(args.print0);
+
+        let args = parse(&["--choose-dir", "/tmp/out"]).unwrap();
+        assert_eq!(
+            args.picker,
+            Some((PickerMode::Directory, PathBuf::from("/tmp/out")))
+        );
+    }
+
+    #[test]
+    fn value_options_are_read() {
+        let args = parse(&["--cwd-file", "/tmp/cwd", "-s", "work", "-c", "sfm.toml"]).unwrap();
+        assert_eq!(args.cwd_file, Some(PathBuf::from("/tmp/cwd")));
+        assert_eq!(args.session_name, Some("work".to_string()));
+        assert_eq!(args.config_path, Some(PathBuf::from("sfm.toml")));
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        assert!(parse(&["--cwd-file"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+        assert!(parse(&["/a", "/b", "/c"]).is_err());
+        assert!(parse(&["--session", "../etc"]).is_err());
+        assert!(parse(&["--session", ".."]).is_err());
+    }
+
+    #[test]
+    fn conflicting_options_are_rejected() {
+        assert!(parse(&["--choose-files", "-", "--choose-dir", "-"]).is_err());
+        assert!(parse(&["--session", "work", "--no-session"]).is_err());
+        assert!(parse(&["--print0"]).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{
+            AppAction, BookmarkAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide,
+        },
+        bookmarks::Bookmark,
+        file_system::FileSystem,
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+#[derive(Clone, Default)]
+pub struct BookmarksModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    list_selector: String,
+    modal_style: ModalStyle,
+}
+
+impl BookmarksModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        list_selector: String,
+        modal_style: ModalStyle,
+    ) -> Self {
+        BookmarksModalComponentProps {
+            panel_side,
+            panel_tab,
+            list_selector,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct BookmarksModalComponentState {
+    list_state: ListState,
+    bookmarks: Vec<Bookmark>,
+    edited_path: Option<String>,
+}
+
+pub struct BookmarksModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<BookmarksModalComponentProps, BookmarksModalComponentState>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> BookmarksModalComponent<TFileSystem> {
+    pub fn new(props: BookmarksModalComponentProps, bookmarks: Vec<Bookmark>) -> Self {
+        let mut list_state = ListState::default();
+        if !bookmarks.is_empty() {
+            list_state.select(Some(0));
+        }
+
+        BookmarksModalComponent {
+            base: ComponentBase::new(
+                Some(props),
+                Some(BookmarksModalComponentState {
+                    list_state,
+                    bookmarks,
+                    edited_path: None,
+                }),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
+    fn selected_bookmark(&self) -> Option<Bookmark> {
+        let local_state = self.base.get_state().unwrap();
+        local_state
+            .list_state
+            .selected()
+            .and_then(|idx| local_state.bookmarks.get(idx).cloned())
+    }
+
+    fn reload_bookmarks(&mut self, store: &Store<AppState<TFileSystem>, FileManagerActions>) {
+        let bookmarks = store.get_state().bookmarks.list();
+        self.base.set_state(|mut current_state| {
+            let selected = match current_state.list_state.selected() {
+                Some(_) if bookmarks.is_empty() => None,
+                Some(idx) if idx >= bookmarks.len() => Some(bookmarks.len() - 1),
+                selected => selected,
+            };
+            current_state.list_state.select(selected);
+            BookmarksModalComponentState {
+                bookmarks: bookmarks.clone(),
+                edited_path: None,
+                ..current_state
+            }
+        });
+    }
+
+    fn handle_edit(
+        &mut self,
+        key_evt: crossterm::event::KeyEvent,
+        edited_path: String,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+            if let Some(bookmark) = self.selected_bookmark() {
+                if !edited_path.is_empty() {
+                    store.dispatch(FileManagerActions::Bookmark(BookmarkAction::Add {
+                        key: bookmark.key,
+                        path: PathBuf::from(edited_path),
+                    }));
+                }
+            }
+            self.reload_bookmarks(store);
+            return true;
+        }
+
+        if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+            self.base
+                .set_state(|current_state| BookmarksModalComponentState {
+                    edited_path: None,
+                    ..current_state
+                });
+            return true;
+        }
+
+        let mut edited_path = edited_path;
+        match key_evt.code {
+            KeyCode::Char(c) => {
+                if key_evt.modifiers == KeyModifiers::SHIFT {
+                    edited_path = format!("{}{}", edited_path, c.to_uppercase());
+                } else {
+                    edited_path.push(c);
+                }
+            }
+            KeyCode::Backspace => {
+                edited_path.pop();
+            }
+            _ => return false,
+        };
+        self.base
+            .set_state(|current_state| BookmarksModalComponentState {
+                edited_path: Some(edited_path.clone()),
+                ..current_state
+            });
+
+        true
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for BookmarksModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if let Some(edited_path) = local_state.edited_path {
+                return self.handle_edit(key_evt, edited_path, store);
+            }
+
+            let bookmarks_count = local_state.bookmarks.len();
+            if state.config.keyboard_cfg.move_up.is_pressed(key_evt) && bookmarks_count > 0 {
+                let next_item = match local_state.list_state.selected() {
+                    Some(current) => {
+                        if current == 0 {
+                            bookmarks_count - 1
+                        } else {
+                            current - 1
+                        }
+                    }
+                    None => 0,
+                };
+                self.base.set_state(|mut current_state| {
+                    current_state.list_state.select(Some(next_item));
+                    BookmarksModalComponentState {
+                        list_state: current_state.list_state,
+                        ..current_state
+                    }
+                });
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_down.is_pressed(key_evt) && bookmarks_count > 0 {
+                let next_item = match local_state.list_state.selected() {
+                    Some(current) => {
+                        if current >= bookmarks_count - 1 {
+                            0
+                        } else {
+                            current + 1
+                        }
+                    }
+                    None => 0,
+                };
+                self.base.set_state(|mut current_state| {
+                    current_state.list_state.select(Some(next_item));
+                    BookmarksModalComponentState {
+                        list_state: current_state.list_state,
+                        ..current_state
+                    }
+                });
+                return true;
+            }
+
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+                if let Some(bookmark) = self.selected_bookmark() {
+                    store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                    store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
+                        panel: PanelInfo {
+                            side: props.panel_side.unwrap(),
+                            tab: props.panel_tab,
+                            path: bookmark.path,
+                        },
+                        in_new_tab: false,
+                    }));
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.rename.is_pressed(key_evt) {
+                if let Some(bookmark) = self.selected_bookmark() {
+                    self.base
+                        .set_state(|current_state| BookmarksModalComponentState {
+                            edited_path: Some(bookmark.path.to_str().unwrap_or("").to_string()),
+                            ..current_state
+                        });
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.delete.is_pressed(key_evt) {
+                if let Some(bookmark) = self.selected_bookmark() {
+                    // Bookmarks from config file can be changed only in config file
+                    if !bookmark.is_static {
+                        store.dispatch(FileManagerActions::Bookmark(BookmarkAction::Delete {
+                            key: bookmark.key,
+                        }));
+                        self.reload_bookmarks(store);
+                    }
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+        }
+        false
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(80, 50, area)
+        } else {
+            create_modal_layout(80, 50, frame.size())
+        };
+
+        let props = self.base.get_props().unwrap();
+        let mut local_state = self.base.get_state().unwrap();
+
+        if let Some(edited_path) = local_state.edited_path {
+            let key = self
+                .selected_bookmark()
+                .map(|bookmark| bookmark.key.to_string())
+                .unwrap_or_default();
+            let block = Block::default()
+                .title(Spans::from(vec![
+                    Span::from("| "),
+                    Span::from(format!("Edit bookmark '{}':", key)),
+                    Span::from(" |"),
+                ]))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(props.modal_style.border_color))
+                .border_type(tui::widgets::BorderType::Thick)
+                .style(Style::default());
+
+            let paragraph = Paragraph::new(edited_path)
+                .block(block)
+                .alignment(tui::layout::Alignment::Left);
+
+            frame.render_widget(Clear, layout);
+            frame.render_widget(paragraph, layout);
+            return;
+        }
+
+        let items: Vec<ListItem> = local_state
+            .bookmarks
+            .iter()
+            .map(|bookmark| {
+                let mut spans = vec![
+                    Span::from(format!("{}  ", bookmark.key)),
+                    Span::from(bookmark.path.to_str().unwrap_or("").to_string()),
+                ];
+                if bookmark.is_static {
+                    spans.push(Span::from("  (config)"));
+                }
+                ListItem::new(Spans::from(spans))
+            })
+            .collect();
+
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from("Bookmarks"),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default().bg(tui::style::Color::Reset));
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(props.modal_style.selected_element_background)
+                    .fg(props.modal_style.selected_element_foreground),
+            )
+            .highlight_symbol(props.list_selector.as_str());
+
+        frame.render_widget(Clear, layout);
+        frame.render_stateful_widget(list, layout, &mut local_state.list_state);
+    }
+}
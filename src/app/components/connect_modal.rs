@@ -0,0 +1,163 @@
+use std::{fmt::Debug, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide},
+        file_system::{sftp_file_system::SFTP_PREFIX, FileSystem},
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+#[derive(Clone, Default)]
+pub struct ConnectModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    modal_style: ModalStyle,
+}
+
+impl ConnectModalComponentProps {
+    pub fn new(panel_side: Option<PanelSide>, panel_tab: TabIdx, modal_style: ModalStyle) -> Self {
+        Self {
+            panel_side,
+            panel_tab,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConnectModalComponentState {
+    input: String,
+}
+
+pub struct ConnectModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<ConnectModalComponentProps, ConnectModalComponentState>,
+    _maker: std::marker::PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> ConnectModalComponent<TFileSystem> {
+    pub fn with_props(props: ConnectModalComponentProps) -> Self {
+        ConnectModalComponent {
+            base: ComponentBase::new(Some(props), Some(ConnectModalComponentState::default())),
+            _maker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for ConnectModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) && !local_state.input.is_empty()
+            {
+                let remote_path = if local_state.input.contains('/') {
+                    format!("{}{}", SFTP_PREFIX, local_state.input)
+                } else {
+                    format!("{}{}/~", SFTP_PREFIX, local_state.input)
+                };
+
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
+                    panel: PanelInfo {
+                        side: props.panel_side.unwrap(),
+                        tab: props.panel_tab,
+                        path: PathBuf::from(remote_path),
+                    },
+                    in_new_tab: true,
+                }));
+                return true;
+            }
+
+            match key_evt.code {
+                KeyCode::Char(c) => {
+                    self.base.set_state(|current_state| {
+                        let mut current_text = current_state.input.clone();
+                        if key_evt.modifiers == KeyModifiers::SHIFT {
+                            current_text = format!("{}{}", current_text, c.to_uppercase());
+                        } else {
+                            current_text.push(c);
+                        }
+
+                        ConnectModalComponentState {
+                            input: current_text,
+                        }
+                    });
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    self.base.set_state(|current_state| {
+                        let mut current_text = current_state.input.clone();
+                        current_text.pop();
+
+                        ConnectModalComponentState {
+                            input: current_text,
+                        }
+                    });
+                    return true;
+                }
+                _ => {}
+            };
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+        }
+        false
+    }
+
+    fn render<TBackend: tui::backend::Backend>(
+        &self,
+        frame: &mut tui::Frame<TBackend>,
+        area: Option<tui::layout::Rect>,
+    ) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(50, 10, area)
+        } else {
+            create_modal_layout(50, 10, frame.size())
+        };
+
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from("Connect to (host or user@host[:port][/path]):"),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+
+        let paragraph = Paragraph::new(local_state.input)
+            .block(block)
+            .alignment(tui::layout::Alignment::Center);
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(paragraph, layout);
+    }
+}
@@ -0,0 +1,392 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf, time::Duration};
+
+use crossterm::event::KeyCode;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileManagerActions},
+        file_system::{
+            diff::{DiffLine, DiffLoader, DiffRow, DiffTag, DiffView, FileDiff},
+            FileSystem,
+        },
+        state::AppState,
+    },
+    core::{
+        color_scheme::ColorScheme,
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+// Borders and status line
+const FRAME_HEIGHT: usize = 3;
+// Small files are compared before first tick ends so the modal does not flicker
+const LOAD_WAIT: Duration = Duration::from_millis(20);
+
+#[derive(Clone, Default)]
+pub struct DiffModalComponentProps {
+    old_path: PathBuf,
+    new_path: PathBuf,
+    color_scheme: ColorScheme,
+}
+
+impl DiffModalComponentProps {
+    pub fn new(old_path: PathBuf, new_path: PathBuf, color_scheme: ColorScheme) -> Self {
+        DiffModalComponentProps {
+            old_path,
+            new_path,
+            color_scheme,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct DiffModalComponentState {
+    // First displayed row
+    scroll: usize,
+    unified: bool,
+}
+
+pub struct DiffModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<DiffModalComponentProps, DiffModalComponentState>,
+    loader: Option<DiffLoader>,
+    // None until files are compared on background thread
+    diff: Option<Result<FileDiff, String>>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> DiffModalComponent<TFileSystem> {
+    pub fn new(props: DiffModalComponentProps, file_system: &TFileSystem) -> Self {
+        let loader = DiffLoader::start(file_system, props.old_path.clone(), props.new_path.clone());
+        DiffModalComponent {
+            base: ComponentBase::new(Some(props), Some(DiffModalComponentState::default())),
+            loader: Some(loader),
+            diff: None,
+            _marker: PhantomData,
+        }
+    }
+
+    // Diff takes whole screen, layout is the same as in render
+    fn visible_lines() -> usize {
+        let (_, height) = crossterm::terminal::size().unwrap_or((80, 24));
+        (height as usize).saturating_sub(FRAME_HEIGHT).max(1)
+    }
+
+    fn view(&self) -> Option<&DiffView> {
+        let local_state = self.base.get_state().unwrap();
+        match self.diff {
+            Some(Ok(ref diff)) if local_state.unified => Some(&diff.unified),
+            Some(Ok(ref diff)) => Some(&diff.side_by_side),
+            _ => None,
+        }
+    }
+
+    // Hunk which contains first displayed row
+    fn current_hunk(&self) -> Option<usize> {
+        let local_state = self.base.get_state().unwrap();
+        self.view()?
+            .hunk_starts
+            .iter()
+            .rposition(|start| *start <= local_state.scroll)
+    }
+
+    fn scroll_to(&mut self, row: usize) {
+        let rows_count = self.view().map(|view| view.rows.len()).unwrap_or(0);
+        let scroll = row.min(rows_count.saturating_sub(Self::visible_lines()));
+        self.base
+            .set_state(|current_state| DiffModalComponentState {
+                scroll,
+                ..current_state
+            });
+    }
+
+    fn scroll_by(&mut self, rows: isize) {
+        let local_state = self.base.get_state().unwrap();
+        self.scroll_to((local_state.scroll as isize + rows).max(0) as usize);
+    }
+
+    fn go_to_hunk(&mut self, next: bool) {
+        let local_state = self.base.get_state().unwrap();
+        let target = match self.view() {
+            Some(view) if next => view
+                .hunk_starts
+                .iter()
+                .find(|start| **start > local_state.scroll)
+                .copied(),
+            Some(view) => view
+                .hunk_starts
+                .iter()
+                .rev()
+                .find(|start| **start < local_state.scroll)
+                .copied(),
+            None => None,
+        };
+        if let Some(target) = target {
+            self.scroll_to(target);
+        }
+    }
+
+    // Switching mode keeps the same hunk on screen
+    fn toggle_unified(&mut self) {
+        let current_hunk = self.current_hunk();
+        self.base
+            .set_state(|current_state| DiffModalComponentState {
+                scroll: 0,
+                unified: !current_state.unified,
+            });
+        let target = match (self.view(), current_hunk) {
+            (Some(view), Some(hunk)) => view.hunk_starts.get(hunk).copied(),
+            _ => None,
+        };
+        if let Some(target) = target {
+            self.scroll_to(target);
+        }
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for DiffModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.close.is_pressed(key_evt)
+                || state.config.keyboard_cfg.diff.is_pressed(key_evt)
+            {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_down.is_pressed(key_evt) {
+                self.scroll_by(1);
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_up.is_pressed(key_evt) {
+                self.scroll_by(-1);
+                return true;
+            }
+
+            let page_lines = Self::visible_lines() as isize;
+            match key_evt.code {
+                KeyCode::Down => self.scroll_by(1),
+                KeyCode::Up => self.scroll_by(-1),
+                KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(page_lines),
+                KeyCode::PageUp => self.scroll_by(-page_lines),
+                KeyCode::Home => self.scroll_to(0),
+                KeyCode::End => self.scroll_to(usize::MAX),
+                KeyCode::Char('n') => self.go_to_hunk(true),
+                KeyCode::Char('N') => self.go_to_hunk(false),
+                KeyCode::Char('u') => self.toggle_unified(),
+                _ => {}
+            };
+        }
+        true
+    }
+
+    fn on_tick(&mut self, _store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        if let Some(ref loader) = self.loader {
+            if let Some(diff) = loader.wait(LOAD_WAIT) {
+                self.diff = Some(diff);
+                self.loader = None;
+            }
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, _area: Option<Rect>) {
+        let area = frame.size();
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        let color_scheme = &props.color_scheme;
+
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(format!(
+                    "Diff {} with {}",
+                    props.old_path.to_str().unwrap_or(""),
+                    props.new_path.to_str().unwrap_or("")
+                )),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color_scheme.normal_yellow))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let inner = block.inner(area);
+        let diff_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+            .split(inner);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let message_style = Style::default().fg(color_scheme.normal_yellow);
+        let number_style = Style::default().fg(color_scheme.light_black);
+        let diff = match self.diff {
+            Some(Ok(ref diff)) => diff,
+            None => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled("Comparing files...", message_style)),
+                    diff_layout[0],
+                );
+                return;
+            }
+            Some(Err(ref err)) => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        err.as_str(),
+                        Style::default().fg(color_scheme.normal_red),
+                    )),
+                    diff_layout[0],
+                );
+                return;
+            }
+        };
+        if diff.is_identical() {
+            frame.render_widget(
+                Paragraph::new(Span::styled("Files are identical", message_style)),
+                diff_layout[0],
+            );
+            return;
+        }
+
+        let view = self.view().unwrap();
+        let number_width = diff
+            .old_lines_count
+            .max(diff.new_lines_count)
+            .to_string()
+            .len();
+        let styles = DiffStyles {
+            header: Style::default()
+                .fg(color_scheme.light_cyan)
+                .add_modifier(Modifier::BOLD),
+            number: number_style,
+            delete: Style::default().fg(color_scheme.normal_red),
+            insert: Style::default().fg(color_scheme.normal_green),
+            number_width,
+        };
+        let rows = view
+            .rows
+            .iter()
+            .skip(local_state.scroll)
+            .take(diff_layout[0].height as usize);
+        if local_state.unified {
+            let lines: Vec<Spans> = rows
+                .map(|row| match row {
+                    DiffRow::Header(header) => {
+                        Spans::from(Span::styled(header.as_str(), styles.header))
+                    }
+                    DiffRow::Unified(line) => unified_line(line, &styles),
+                    DiffRow::SideBySide(..) => Spans::default(),
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(lines), diff_layout[0]);
+        } else {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                .split(diff_layout[0]);
+            let mut old_lines = Vec::new();
+            let mut new_lines = Vec::new();
+            for row in rows {
+                match row {
+                    DiffRow::Header(header) => {
+                        old_lines.push(Spans::from(Span::styled(header.as_str(), styles.header)));
+                        new_lines.push(Spans::default());
+                    }
+                    DiffRow::SideBySide(old_line, new_line) => {
+                        old_lines.push(side_line(old_line.as_ref(), false, &styles));
+                        new_lines.push(side_line(new_line.as_ref(), true, &styles));
+                    }
+                    DiffRow::Unified(_) => {}
+                }
+            }
+            frame.render_widget(Paragraph::new(old_lines), columns[0]);
+            frame.render_widget(Paragraph::new(new_lines), columns[1]);
+        }
+
+        let status = format!(
+            "change {}/{}  n/N - next/previous change, u - {}, esc - close",
+            self.current_hunk().map(|hunk| hunk + 1).unwrap_or(0),
+            view.hunk_starts.len(),
+            if local_state.unified {
+                "side by side"
+            } else {
+                "unified"
+            }
+        );
+        frame.render_widget(
+            Paragraph::new(Span::styled(status, number_style)),
+            diff_layout[1],
+        );
+    }
+}
+
+struct DiffStyles {
+    header: Style,
+    number: Style,
+    delete: Style,
+    insert: Style,
+    number_width: usize,
+}
+
+fn format_number(number: Option<usize>, width: usize) -> String {
+    match number {
+        Some(number) => format!("{:>width$} ", number, width = width),
+        None => " ".repeat(width + 1),
+    }
+}
+
+fn unified_line<'a>(line: &'a DiffLine, styles: &DiffStyles) -> Spans<'a> {
+    let (sign, style) = match line.tag {
+        DiffTag::Equal => (" ", Style::default()),
+        DiffTag::Delete => ("-", styles.delete),
+        DiffTag::Insert => ("+", styles.insert),
+    };
+    Spans::from(vec![
+        Span::styled(
+            format_number(line.old_number, styles.number_width),
+            styles.number,
+        ),
+        Span::styled(
+            format_number(line.new_number, styles.number_width),
+            styles.number,
+        ),
+        Span::styled(format!("{}{}", sign, line.text), style),
+    ])
+}
+
+// Line is missing on one side when other side has inserted or deleted line
+fn side_line<'a>(line: Option<&'a DiffLine>, is_new: bool, styles: &DiffStyles) -> Spans<'a> {
+    match line {
+        Some(line) => {
+            let (number, style) = match line.tag {
+                DiffTag::Equal if is_new => (line.new_number, Style::default()),
+                DiffTag::Equal => (line.old_number, Style::default()),
+                DiffTag::Delete => (line.old_number, styles.delete),
+                DiffTag::Insert => (line.new_number, styles.insert),
+            };
+            Spans::from(vec![
+                Span::styled(format_number(number, styles.number_width), styles.number),
+                Span::styled(line.text.as_str(), style),
+            ])
+        }
+        None => Spans::default(),
+    }
+}
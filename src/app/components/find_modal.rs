@@ -0,0 +1,329 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileManagerActions, PanelSide, TabAction},
+        file_system::{walker::SubtreeWalker, FileSystem},
+        find_query::FindQuery,
+        state::AppState,
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+const MAX_RESULTS: usize = 10000;
+
+const FIELD_LABELS: [&str; 6] = [
+    "Name (glob eg. *.rs)",
+    "Type (f - file, d - directory, l - symlink)",
+    "Size (MIN..MAX eg. 10k..2M)",
+    "Modified (MIN..MAX ago eg. ..7d, 1h..2w)",
+    "Owner (user name or uid)",
+    "Permissions (octal eg. 644, +111 - all bits set)",
+];
+
+#[derive(Clone, Default)]
+pub struct FindModalComponentProps {
+    panel_side: Option<PanelSide>,
+    root: PathBuf,
+    show_hidden: bool,
+    modal_style: ModalStyle,
+}
+
+impl FindModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        root: PathBuf,
+        show_hidden: bool,
+        modal_style: ModalStyle,
+    ) -> Self {
+        FindModalComponentProps {
+            panel_side,
+            root,
+            show_hidden,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct FindModalComponentState {
+    fields: Vec<String>,
+    active_field: usize,
+    error: Option<String>,
+    respect_gitignore: bool,
+}
+
+pub struct FindModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<FindModalComponentProps, FindModalComponentState>,
+    results: Vec<PathBuf>,
+    walker: Option<SubtreeWalker>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> FindModalComponent<TFileSystem> {
+    pub fn new(props: FindModalComponentProps, respect_gitignore: bool) -> Self {
+        FindModalComponent {
+            base: ComponentBase::new(
+                Some(props),
+                Some(FindModalComponentState {
+                    fields: vec![String::new(); FIELD_LABELS.len()],
+                    active_field: 0,
+                    error: None,
+                    respect_gitignore,
+                }),
+            ),
+            results: Vec::new(),
+            walker: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn start_find(&mut self) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        let fields = &local_state.fields;
+        let query = match FindQuery::parse(
+            &fields[0], &fields[1], &fields[2], &fields[3], &fields[4], &fields[5],
+        ) {
+            Ok(query) => query,
+            Err(error) => {
+                self.set_error(Some(error));
+                return;
+            }
+        };
+
+        self.results.clear();
+        self.walker = Some(SubtreeWalker::start_filtered(
+            props.root,
+            local_state.respect_gitignore,
+            props.show_hidden,
+            Box::new(move |entry, metadata| {
+                let file_name = entry
+                    .path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or("");
+                query.matches(file_name, metadata)
+            }),
+        ));
+        self.set_error(None);
+    }
+
+    fn set_error(&mut self, error: Option<String>) {
+        self.base
+            .set_state(|current_state| FindModalComponentState {
+                error: error.clone(),
+                ..current_state
+            });
+    }
+
+    fn finish_find(&mut self, store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        self.walker = None;
+        if self.results.is_empty() {
+            self.set_error(Some("No matches".to_string()));
+            return;
+        }
+
+        let props = self.base.get_props().unwrap();
+        let mut paths = std::mem::take(&mut self.results);
+        paths.sort();
+        // Walker is stopped once the cap is reached, so more matches may exist
+        let name = if paths.len() >= MAX_RESULTS {
+            format!("search results (first {})", MAX_RESULTS)
+        } else {
+            String::from("search results")
+        };
+        store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+        store.dispatch(FileManagerActions::Tab(TabAction::ShowSearchResults {
+            panel_side: props.panel_side.unwrap(),
+            root: props.root,
+            name,
+            paths,
+        }));
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for FindModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            // Form is locked until running search is finished
+            if self.walker.is_some() {
+                return true;
+            }
+
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+                self.start_find();
+                return true;
+            }
+
+            if state
+                .config
+                .keyboard_cfg
+                .toggle_gitignore
+                .is_pressed(key_evt)
+            {
+                self.base
+                    .set_state(|current_state| FindModalComponentState {
+                        respect_gitignore: !current_state.respect_gitignore,
+                        ..current_state
+                    });
+                return true;
+            }
+
+            let fields_count = local_state.fields.len();
+            match key_evt.code {
+                KeyCode::Tab | KeyCode::Down => {
+                    self.base
+                        .set_state(|current_state| FindModalComponentState {
+                            active_field: (current_state.active_field + 1) % fields_count,
+                            ..current_state
+                        });
+                    return true;
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    self.base
+                        .set_state(|current_state| FindModalComponentState {
+                            active_field: (current_state.active_field + fields_count - 1)
+                                % fields_count,
+                            ..current_state
+                        });
+                    return true;
+                }
+                KeyCode::Char(c) => {
+                    self.base.set_state(|mut current_state| {
+                        let field = &mut current_state.fields[current_state.active_field];
+                        if key_evt.modifiers == KeyModifiers::SHIFT {
+                            field.push_str(c.to_uppercase().to_string().as_str());
+                        } else {
+                            field.push(c);
+                        }
+                        current_state
+                    });
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    self.base.set_state(|mut current_state| {
+                        current_state.fields[current_state.active_field].pop();
+                        current_state
+                    });
+                    return true;
+                }
+                _ => {}
+            };
+        }
+        false
+    }
+
+    fn on_tick(&mut self, store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        if let Some(ref mut walker) = self.walker {
+            let new_entries = walker.poll();
+            let finished = walker.is_finished();
+            self.results
+                .extend(new_entries.into_iter().map(|entry| entry.path));
+            if finished || self.results.len() >= MAX_RESULTS {
+                self.results.truncate(MAX_RESULTS);
+                self.finish_find(store);
+            }
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(80, 80, area)
+        } else {
+            create_modal_layout(80, 80, frame.size())
+        };
+
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+
+        let title = format!(
+            "Find in {}{}",
+            props.root.to_str().unwrap_or(""),
+            if local_state.respect_gitignore {
+                " [gitignore]"
+            } else {
+                ""
+            },
+        );
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(title),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let inner = block.inner(layout);
+
+        let mut constraints: Vec<Constraint> =
+            FIELD_LABELS.iter().map(|_| Constraint::Length(3)).collect();
+        constraints.push(Constraint::Min(1));
+        let form_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner);
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(block, layout);
+        for (idx, label) in FIELD_LABELS.iter().enumerate() {
+            let border_style = if idx == local_state.active_field {
+                Style::default().fg(props.modal_style.border_color)
+            } else {
+                Style::default()
+            };
+            let field_block = Block::default()
+                .title(Spans::from(vec![
+                    Span::from(" "),
+                    Span::from(*label),
+                    Span::from(" "),
+                ]))
+                .borders(Borders::ALL)
+                .border_style(border_style);
+            let paragraph = Paragraph::new(local_state.fields[idx].clone()).block(field_block);
+            frame.render_widget(paragraph, form_layout[idx]);
+        }
+
+        let status = match (&self.walker, &local_state.error) {
+            (Some(_), _) => format!("Searching... {} found", self.results.len()),
+            (None, Some(error)) => error.clone(),
+            (None, None) => "enter - search, tab - next field, esc - close".to_string(),
+        };
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(props.modal_style.border_color)),
+            form_layout[FIELD_LABELS.len()],
+        );
+    }
+}
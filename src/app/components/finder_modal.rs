@@ -0,0 +1,468 @@
+use std::{cmp::Reverse, fmt::Debug, marker::PhantomData, mem, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{
+            AppAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide, TabAction,
+        },
+        file_system::{
+            walker::{SubtreeWalker, WalkEntry},
+            FileSystem,
+        },
+        fuzzy::{fuzzy_match, FuzzyMatch},
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+const MAX_MATCHES: usize = 500;
+
+#[derive(Clone)]
+pub struct FinderModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    root: PathBuf,
+    show_hidden: bool,
+    list_selector: String,
+    modal_style: ModalStyle,
+    highlight_color: Color,
+}
+
+impl FinderModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        root: PathBuf,
+        show_hidden: bool,
+        list_selector: String,
+        modal_style: ModalStyle,
+        highlight_color: Color,
+    ) -> Self {
+        FinderModalComponentProps {
+            panel_side,
+            panel_tab,
+            root,
+            show_hidden,
+            list_selector,
+            modal_style,
+            highlight_color,
+        }
+    }
+}
+
+impl Default for FinderModalComponentProps {
+    fn default() -> Self {
+        FinderModalComponentProps {
+            panel_side: None,
+            panel_tab: 0,
+            root: PathBuf::new(),
+            show_hidden: false,
+            list_selector: String::new(),
+            modal_style: ModalStyle::default(),
+            highlight_color: Color::Green,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct FinderModalComponentState {
+    input: String,
+    list_state: ListState,
+    respect_gitignore: bool,
+}
+
+pub struct FinderModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<FinderModalComponentProps, FinderModalComponentState>,
+    // Walked entries can be counted in hundreds of thousands so they are kept
+    // outside of component state which is cloned on every access
+    entries: Vec<WalkEntry>,
+    // Every entry matching input, only best of them are kept in matches
+    candidates: Vec<usize>,
+    candidates_input: String,
+    matches: Vec<(usize, FuzzyMatch)>,
+    walker: Option<SubtreeWalker>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> FinderModalComponent<TFileSystem> {
+    pub fn new(props: FinderModalComponentProps, respect_gitignore: bool) -> Self {
+        let mut finder = FinderModalComponent {
+            base: ComponentBase::new(
+                Some(props),
+                Some(FinderModalComponentState {
+                    input: String::new(),
+                    list_state: ListState::default(),
+                    respect_gitignore,
+                }),
+            ),
+            entries: Vec::new(),
+            candidates: Vec::new(),
+            candidates_input: String::new(),
+            matches: Vec::new(),
+            walker: None,
+            _marker: PhantomData,
+        };
+        finder.start_walk();
+
+        finder
+    }
+
+    fn start_walk(&mut self) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        self.entries.clear();
+        self.candidates.clear();
+        self.candidates_input = local_state.input.clone();
+        self.matches.clear();
+        self.walker = Some(SubtreeWalker::start(
+            props.root,
+            local_state.respect_gitignore,
+            props.show_hidden,
+        ));
+        self.base.set_state(|mut current_state| {
+            current_state.list_state.select(None);
+            current_state
+        });
+    }
+
+    // Scores entries again after input changed
+    fn filter_matches(&mut self) {
+        let input = self.base.get_state().unwrap().input;
+        // Entries matching longer pattern are subset of entries matching its prefix,
+        // so typing narrows previous candidates instead of scoring everything again
+        let scanned: Vec<usize> = if input.starts_with(self.candidates_input.as_str()) {
+            mem::take(&mut self.candidates)
+        } else {
+            (0..self.entries.len()).collect()
+        };
+        self.candidates.clear();
+        self.matches.clear();
+        self.candidates_input = input;
+        self.score_entries(scanned, false);
+    }
+
+    // Scores entries appended by walker starting from given index
+    fn update_matches(&mut self, from: usize) {
+        self.score_entries((from..self.entries.len()).collect(), true);
+    }
+
+    fn score_entries(&mut self, scanned: Vec<usize>, keep_selection: bool) {
+        let input = &self.candidates_input;
+        for idx in scanned {
+            if let Some(fuzzy) = fuzzy_match(input, &self.entries[idx].relative_path) {
+                self.candidates.push(idx);
+                self.matches.push((idx, fuzzy));
+            }
+        }
+
+        let entries = &self.entries;
+        let sort_key = |(idx, fuzzy): &(usize, FuzzyMatch)| {
+            (Reverse(fuzzy.score), entries[*idx].relative_path.len())
+        };
+        if self.matches.len() > MAX_MATCHES {
+            self.matches
+                .select_nth_unstable_by_key(MAX_MATCHES, sort_key);
+            self.matches.truncate(MAX_MATCHES);
+        }
+        self.matches.sort_by_key(sort_key);
+
+        let matches_count = self.matches.len();
+        self.base.set_state(|mut current_state| {
+            let selected = match current_state.list_state.selected() {
+                _ if matches_count == 0 => None,
+                Some(selected) if keep_selection => Some(selected.min(matches_count - 1)),
+                _ => Some(0),
+            };
+            current_state.list_state.select(selected);
+            current_state
+        });
+    }
+
+    fn jump_to(
+        &self,
+        entry: WalkEntry,
+        in_new_tab: bool,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) {
+        let props = self.base.get_props().unwrap();
+        let panel_side = props.panel_side.unwrap();
+        store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+
+        let dir_path = if entry.is_dir {
+            entry.path.clone()
+        } else {
+            match entry.path.parent() {
+                Some(parent) => parent.to_path_buf(),
+                None => return,
+            }
+        };
+        store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
+            panel: PanelInfo {
+                side: panel_side,
+                tab: props.panel_tab,
+                path: dir_path,
+            },
+            in_new_tab,
+        }));
+
+        if !entry.is_dir {
+            let state = store.get_state();
+            let tab = if in_new_tab {
+                match panel_side {
+                    PanelSide::Left => state.left_panel.tabs.len() - 1,
+                    PanelSide::Right => state.right_panel.tabs.len() - 1,
+                }
+            } else {
+                props.panel_tab
+            };
+            store.dispatch(FileManagerActions::Tab(TabAction::SelectPath {
+                panel_side,
+                tab,
+                path: entry.path,
+            }));
+        }
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for FinderModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            let in_new_tab = state.config.keyboard_cfg.open_as_tab.is_pressed(key_evt);
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) || in_new_tab {
+                let selected = local_state
+                    .list_state
+                    .selected()
+                    .and_then(|idx| self.matches.get(idx))
+                    .map(|(entry_idx, _)| self.entries[*entry_idx].clone());
+                if let Some(entry) = selected {
+                    self.jump_to(entry, in_new_tab, store);
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            if state
+                .config
+                .keyboard_cfg
+                .toggle_gitignore
+                .is_pressed(key_evt)
+            {
+                self.base
+                    .set_state(|current_state| FinderModalComponentState {
+                        respect_gitignore: !current_state.respect_gitignore,
+                        ..current_state
+                    });
+                self.start_walk();
+                return true;
+            }
+
+            let matches_count = self.matches.len();
+            match key_evt.code {
+                KeyCode::Up | KeyCode::Down if matches_count > 0 => {
+                    let next_item = match (key_evt.code, local_state.list_state.selected()) {
+                        (KeyCode::Up, Some(0)) => matches_count - 1,
+                        (KeyCode::Up, Some(current)) => current - 1,
+                        (_, Some(current)) if current < matches_count - 1 => current + 1,
+                        _ => 0,
+                    };
+                    self.base.set_state(|mut current_state| {
+                        current_state.list_state.select(Some(next_item));
+                        current_state
+                    });
+                    return true;
+                }
+                KeyCode::Char(c) => {
+                    self.base.set_state(|current_state| {
+                        let mut input = current_state.input.clone();
+                        if key_evt.modifiers == KeyModifiers::SHIFT {
+                            input = format!("{}{}", input, c.to_uppercase());
+                        } else {
+                            input.push(c);
+                        }
+                        FinderModalComponentState {
+                            input,
+                            ..current_state
+                        }
+                    });
+                    self.filter_matches();
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    self.base.set_state(|current_state| {
+                        let mut input = current_state.input.clone();
+                        input.pop();
+                        FinderModalComponentState {
+                            input,
+                            ..current_state
+                        }
+                    });
+                    self.filter_matches();
+                    return true;
+                }
+                _ => {}
+            };
+        }
+        false
+    }
+
+    fn on_tick(&mut self, _store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        if let Some(ref mut walker) = self.walker {
+            let mut new_entries = walker.poll();
+            let finished = walker.is_finished();
+            if !new_entries.is_empty() {
+                let from = self.entries.len();
+                self.entries.append(&mut new_entries);
+                self.update_matches(from);
+            }
+            if finished {
+                self.walker = None;
+            }
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(90, 80, area)
+        } else {
+            create_modal_layout(90, 80, frame.size())
+        };
+        let modal_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(layout);
+
+        let props = self.base.get_props().unwrap();
+        let mut local_state = self.base.get_state().unwrap();
+
+        let title = format!(
+            "Find: {}/{}{}{}",
+            self.matches.len(),
+            self.entries.len(),
+            if self.walker.is_some() {
+                " scanning..."
+            } else {
+                ""
+            },
+            if local_state.respect_gitignore {
+                " [gitignore]"
+            } else {
+                ""
+            },
+        );
+        let input_block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(title),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let paragraph = Paragraph::new(local_state.input.clone()).block(input_block);
+
+        let highlight_style = Style::default()
+            .fg(props.highlight_color)
+            .add_modifier(Modifier::BOLD);
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|(entry_idx, fuzzy)| {
+                let entry = &self.entries[*entry_idx];
+                let mut spans =
+                    highlight_positions(&entry.relative_path, &fuzzy.positions, highlight_style);
+                if entry.is_dir {
+                    spans.push(Span::from("/"));
+                }
+                ListItem::new(Spans::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(props.modal_style.border_color))
+                    .border_type(tui::widgets::BorderType::Thick),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(props.modal_style.selected_element_background)
+                    .fg(props.modal_style.selected_element_foreground),
+            )
+            .highlight_symbol(props.list_selector.as_str());
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(paragraph, modal_layout[0]);
+        frame.render_stateful_widget(list, modal_layout[1], &mut local_state.list_state);
+    }
+}
+
+// Positions are char indices in ascending order
+pub fn highlight_positions<'a>(
+    text: &str,
+    positions: &[usize],
+    highlight_style: Style,
+) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut current_highlighted = false;
+    let mut positions = positions.iter().peekable();
+    for (idx, c) in text.chars().enumerate() {
+        let highlighted = positions.peek() == Some(&&idx);
+        if highlighted {
+            positions.next();
+        }
+        if highlighted != current_highlighted && !current.is_empty() {
+            spans.push(styled_span(
+                current.clone(),
+                current_highlighted,
+                highlight_style,
+            ));
+            current.clear();
+        }
+        current_highlighted = highlighted;
+        current.push(c);
+    }
+    if !current.is_empty() {
+        spans.push(styled_span(current, current_highlighted, highlight_style));
+    }
+
+    spans
+}
+
+fn styled_span<'a>(text: String, highlighted: bool, highlight_style: Style) -> Span<'a> {
+    if highlighted {
+        Span::styled(text, highlight_style)
+    } else {
+        Span::from(text)
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide},
+        config::icon_cfg::IconsConfig,
+        file_system::{
+            file_system_item::FileSystemItem, functions::expand_if_contains_tilde, FileSystem,
+        },
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+#[derive(Clone, Default)]
+pub struct GoToModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    dir_path: PathBuf,
+    modal_style: ModalStyle,
+}
+
+impl GoToModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        dir_path: PathBuf,
+        modal_style: ModalStyle,
+    ) -> Self {
+        GoToModalComponentProps {
+            panel_side,
+            panel_tab,
+            dir_path,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct GoToModalComponentState {
+    input: String,
+    completions: Vec<String>,
+    error: Option<String>,
+}
+
+pub struct GoToModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<GoToModalComponentProps, GoToModalComponentState>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> GoToModalComponent<TFileSystem> {
+    pub fn new(props: GoToModalComponentProps) -> Self {
+        GoToModalComponent {
+            base: ComponentBase::new(Some(props), Some(GoToModalComponentState::default())),
+            _marker: PhantomData,
+        }
+    }
+
+    fn set_input(&mut self, input: String, completions: Vec<String>) {
+        self.base.set_state(|_| GoToModalComponentState {
+            input: input.clone(),
+            completions: completions.clone(),
+            error: None,
+        });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for GoToModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            let in_new_tab = state.config.keyboard_cfg.open_as_tab.is_pressed(key_evt);
+            if (state.config.keyboard_cfg.accept.is_pressed(key_evt) || in_new_tab)
+                && !local_state.input.is_empty()
+            {
+                let path = resolve_path(&local_state.input, &props.dir_path);
+                if let Some(dir_info) = state.file_system.get_dir_info(&path) {
+                    store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                    store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
+                        panel: PanelInfo {
+                            side: props.panel_side.unwrap(),
+                            tab: props.panel_tab,
+                            path: dir_info.path,
+                        },
+                        in_new_tab,
+                    }));
+                } else {
+                    self.base
+                        .set_state(|current_state| GoToModalComponentState {
+                            error: Some("Not a directory".to_string()),
+                            ..current_state
+                        });
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            match key_evt.code {
+                KeyCode::Tab => {
+                    let (input, completions) = complete_path(
+                        &local_state.input,
+                        &props.dir_path,
+                        &state.file_system,
+                        &state.config.icons,
+                    );
+                    self.set_input(input, completions);
+                    return true;
+                }
+                KeyCode::Char(c) => {
+                    let mut input = local_state.input.clone();
+                    if key_evt.modifiers == KeyModifiers::SHIFT {
+                        input = format!("{}{}", input, c.to_uppercase());
+                    } else {
+                        input.push(c);
+                    }
+                    self.set_input(input, Vec::new());
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    let mut input = local_state.input.clone();
+                    input.pop();
+                    self.set_input(input, Vec::new());
+                    return true;
+                }
+                _ => {}
+            };
+        }
+        false
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+
+        let height = if local_state.completions.is_empty() {
+            10
+        } else {
+            50
+        };
+        let layout = if let Some(area) = area {
+            create_modal_layout(80, height, area)
+        } else {
+            create_modal_layout(80, height, frame.size())
+        };
+
+        let title = match local_state.error {
+            Some(ref error) => format!("Go to: {}", error),
+            None => "Go to:".to_string(),
+        };
+        let input_block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(title),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let paragraph = Paragraph::new(local_state.input.clone()).block(input_block);
+
+        frame.render_widget(Clear, layout);
+        if local_state.completions.is_empty() {
+            frame.render_widget(paragraph, layout);
+        } else {
+            let modal_layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+                .split(layout);
+
+            let items: Vec<ListItem> = local_state
+                .completions
+                .iter()
+                .map(|name| ListItem::new(Spans::from(vec![Span::from(name.clone())])))
+                .collect();
+            let list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(props.modal_style.border_color))
+                    .border_type(tui::widgets::BorderType::Thick),
+            );
+
+            frame.render_widget(paragraph, modal_layout[0]);
+            frame.render_widget(list, modal_layout[1]);
+        }
+    }
+}
+
+fn resolve_path(input: &str, dir_path: &Path) -> PathBuf {
+    let path = expand_if_contains_tilde(input).unwrap_or(PathBuf::from(input));
+    if path.is_absolute() {
+        path
+    } else {
+        dir_path.join(path)
+    }
+}
+
+// Completes last path component with directory names, when more than one
+// directory matches input is extended to their common prefix
+fn complete_path<TFileSystem: FileSystem>(
+    input: &str,
+    dir_path: &Path,
+    file_system: &TFileSystem,
+    icons: &IconsConfig,
+) -> (String, Vec<String>) {
+    let (parent_input, prefix) = match input.rfind('/') {
+        Some(idx) => (&input[..idx + 1], &input[idx + 1..]),
+        None => ("", input),
+    };
+    let parent = if parent_input.is_empty() {
+        dir_path.to_path_buf()
+    } else {
+        resolve_path(parent_input, dir_path)
+    };
+
+    let names: Vec<String> = file_system
+        .list_dir(&parent, icons)
+        .iter()
+        .filter_map(|item| match item {
+            FileSystemItem::Directory(_) => Some(item.get_name()),
+            _ => None,
+        })
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+
+    match names.len() {
+        0 => (input.to_string(), names),
+        1 => (format!("{}{}/", parent_input, names[0]), Vec::new()),
+        _ => {
+            let mut common_prefix = names[0].clone();
+            for name in names.iter().skip(1) {
+                while !name.starts_with(common_prefix.as_str()) {
+                    common_prefix.pop();
+                }
+            }
+
+            (format!("{}{}", parent_input, common_prefix), names)
+        }
+    }
+}
@@ -0,0 +1,432 @@
+use std::{fmt::Debug, marker::PhantomData, ops::Range, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use regex::{Regex, RegexBuilder};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileAction, FileManagerActions, PanelInfo, PanelSide},
+        file_system::{
+            grep::{ContentSearch, GrepMatch},
+            FileSystem,
+        },
+        search_filter::regex_error_message,
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, finder_modal::highlight_positions, ModalStyle};
+
+const MAX_MATCHES: usize = 2000;
+
+#[derive(Clone)]
+pub struct GrepModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    root: PathBuf,
+    show_hidden: bool,
+    list_selector: String,
+    modal_style: ModalStyle,
+    highlight_color: Color,
+}
+
+impl GrepModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        root: PathBuf,
+        show_hidden: bool,
+        list_selector: String,
+        modal_style: ModalStyle,
+        highlight_color: Color,
+    ) -> Self {
+        GrepModalComponentProps {
+            panel_side,
+            panel_tab,
+            root,
+            show_hidden,
+            list_selector,
+            modal_style,
+            highlight_color,
+        }
+    }
+}
+
+impl Default for GrepModalComponentProps {
+    fn default() -> Self {
+        GrepModalComponentProps {
+            panel_side: None,
+            panel_tab: 0,
+            root: PathBuf::new(),
+            show_hidden: false,
+            list_selector: String::new(),
+            modal_style: ModalStyle::default(),
+            highlight_color: Color::Green,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct GrepModalComponentState {
+    input: String,
+    // Pattern of last started search, enter opens selected match while input is unchanged
+    searched_input: Option<String>,
+    list_state: ListState,
+    respect_gitignore: bool,
+    error: Option<String>,
+}
+
+pub struct GrepModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<GrepModalComponentProps, GrepModalComponentState>,
+    matches: Vec<GrepMatch>,
+    files_count: usize,
+    pattern: Option<Regex>,
+    search: Option<ContentSearch>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> GrepModalComponent<TFileSystem> {
+    pub fn new(props: GrepModalComponentProps, respect_gitignore: bool) -> Self {
+        GrepModalComponent {
+            base: ComponentBase::new(
+                Some(props),
+                Some(GrepModalComponentState {
+                    respect_gitignore,
+                    ..GrepModalComponentState::default()
+                }),
+            ),
+            matches: Vec::new(),
+            files_count: 0,
+            pattern: None,
+            search: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn start_search(&mut self) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        self.matches.clear();
+        self.files_count = 0;
+        self.search = None;
+
+        // Smart case as in panel search
+        let case_insensitive = !local_state.input.chars().any(|c| c.is_uppercase());
+        let (pattern, error) = match RegexBuilder::new(&local_state.input)
+            .case_insensitive(case_insensitive)
+            .build()
+        {
+            Ok(pattern) => (Some(pattern), None),
+            Err(err) => (None, Some(regex_error_message(&err))),
+        };
+        if let Some(ref pattern) = pattern {
+            self.search = Some(ContentSearch::start(
+                props.root,
+                pattern.clone(),
+                local_state.respect_gitignore,
+                props.show_hidden,
+            ));
+        }
+        self.pattern = pattern;
+
+        self.base.set_state(|mut current_state| {
+            current_state.searched_input = Some(current_state.input.clone());
+            current_state.error = error.clone();
+            current_state.list_state.select(None);
+            current_state
+        });
+    }
+
+    fn set_input(&mut self, input: String) {
+        self.base
+            .set_state(|current_state| GrepModalComponentState {
+                input: input.clone(),
+                ..current_state
+            });
+    }
+
+    fn open_match(
+        &self,
+        grep_match: GrepMatch,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) {
+        let props = self.base.get_props().unwrap();
+        store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+        store.dispatch(FileManagerActions::File(FileAction::OpenAtLine {
+            panel: PanelInfo {
+                side: props.panel_side.unwrap(),
+                tab: props.panel_tab,
+                path: grep_match.path,
+            },
+            line: grep_match.line_number,
+        }));
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for GrepModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+                if local_state.searched_input.as_ref() == Some(&local_state.input) {
+                    let selected = local_state
+                        .list_state
+                        .selected()
+                        .and_then(|idx| self.matches.get(idx))
+                        .cloned();
+                    if let Some(grep_match) = selected {
+                        self.open_match(grep_match, store);
+                    }
+                } else if !local_state.input.is_empty() {
+                    self.start_search();
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            if state
+                .config
+                .keyboard_cfg
+                .toggle_gitignore
+                .is_pressed(key_evt)
+            {
+                self.base
+                    .set_state(|current_state| GrepModalComponentState {
+                        respect_gitignore: !current_state.respect_gitignore,
+                        ..current_state
+                    });
+                if local_state.searched_input.is_some() {
+                    self.start_search();
+                }
+                return true;
+            }
+
+            let matches_count = self.matches.len();
+            match key_evt.code {
+                KeyCode::Up | KeyCode::Down if matches_count > 0 => {
+                    let next_item = match (key_evt.code, local_state.list_state.selected()) {
+                        (KeyCode::Up, Some(0)) => matches_count - 1,
+                        (KeyCode::Up, Some(current)) => current - 1,
+                        (_, Some(current)) if current < matches_count - 1 => current + 1,
+                        _ => 0,
+                    };
+                    self.base.set_state(|mut current_state| {
+                        current_state.list_state.select(Some(next_item));
+                        current_state
+                    });
+                    return true;
+                }
+                KeyCode::Char(c) => {
+                    let mut input = local_state.input.clone();
+                    if key_evt.modifiers == KeyModifiers::SHIFT {
+                        input = format!("{}{}", input, c.to_uppercase());
+                    } else {
+                        input.push(c);
+                    }
+                    self.set_input(input);
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    let mut input = local_state.input.clone();
+                    input.pop();
+                    self.set_input(input);
+                    return true;
+                }
+                _ => {}
+            };
+        }
+        false
+    }
+
+    fn on_tick(&mut self, _store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        if let Some(ref mut search) = self.search {
+            let new_matches = search.poll();
+            let finished = search.is_finished();
+            if !new_matches.is_empty() {
+                for grep_match in new_matches.into_iter() {
+                    if self.matches.last().map(|last| &last.path) != Some(&grep_match.path) {
+                        self.files_count += 1;
+                    }
+                    self.matches.push(grep_match);
+                }
+                self.matches.truncate(MAX_MATCHES);
+                self.base.set_state(|mut current_state| {
+                    if current_state.list_state.selected().is_none() {
+                        current_state.list_state.select(Some(0));
+                    }
+                    current_state
+                });
+            }
+            if finished || self.matches.len() >= MAX_MATCHES {
+                self.search = None;
+            }
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(90, 80, area)
+        } else {
+            create_modal_layout(90, 80, frame.size())
+        };
+        let modal_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(layout);
+
+        let props = self.base.get_props().unwrap();
+        let mut local_state = self.base.get_state().unwrap();
+
+        let title = match local_state.error {
+            Some(ref error) => format!("Grep: {}", error),
+            None if local_state.searched_input.is_some() => format!(
+                "Grep: {} matches in {} files{}{}",
+                self.matches.len(),
+                self.files_count,
+                if self.search.is_some() {
+                    " searching..."
+                } else {
+                    ""
+                },
+                if local_state.respect_gitignore {
+                    " [gitignore]"
+                } else {
+                    ""
+                },
+            ),
+            None => format!(
+                "Grep (regex, enter to search){}",
+                if local_state.respect_gitignore {
+                    " [gitignore]"
+                } else {
+                    ""
+                }
+            ),
+        };
+        let input_block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(title),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let paragraph = Paragraph::new(local_state.input.clone()).block(input_block);
+
+        let highlight_style = Style::default()
+            .fg(props.highlight_color)
+            .add_modifier(Modifier::BOLD);
+        let path_style = Style::default().fg(props.modal_style.border_color);
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|grep_match| {
+                let mut spans = vec![
+                    Span::styled(grep_match.relative_path.clone(), path_style),
+                    Span::from(format!(":{}: ", grep_match.line_number)),
+                ];
+                let positions = match self.pattern {
+                    Some(ref pattern) => {
+                        match_positions(pattern, &grep_match.line, 0..grep_match.line.len())
+                    }
+                    None => Vec::new(),
+                };
+                spans.append(&mut highlight_positions(
+                    &grep_match.line,
+                    &positions,
+                    highlight_style,
+                ));
+                ListItem::new(Spans::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(props.modal_style.border_color))
+                    .border_type(tui::widgets::BorderType::Thick),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(props.modal_style.selected_element_background)
+                    .fg(props.modal_style.selected_element_foreground),
+            )
+            .highlight_symbol(props.list_selector.as_str());
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(paragraph, modal_layout[0]);
+        frame.render_stateful_widget(list, modal_layout[1], &mut local_state.list_state);
+    }
+}
+
+// Char indices (counted from start of byte range) covered by pattern matches in
+// given part of line, highlighting works on chars not bytes
+pub fn match_positions(pattern: &Regex, line: &str, range: Range<usize>) -> Vec<usize> {
+    // Searching from range start keeps anchors and word boundaries of whole line
+    let mut ranges = Vec::new();
+    let mut search_from = range.start;
+    while let Some(found) = pattern.find_at(line, search_from) {
+        if found.start() >= range.end {
+            break;
+        }
+        if found.end() > found.start() {
+            ranges.push(found.start()..found.end().min(range.end));
+            search_from = found.end();
+        } else {
+            // Empty match covers nothing, search continues after next char
+            match line[found.end()..].chars().next() {
+                Some(c) => search_from = found.end() + c.len_utf8(),
+                None => break,
+            }
+        }
+    }
+
+    let mut ranges = ranges.into_iter().peekable();
+    let mut positions = Vec::new();
+    for (char_idx, (byte_idx, _)) in line[range.clone()].char_indices().enumerate() {
+        let byte_idx = range.start + byte_idx;
+        while ranges
+            .peek()
+            .map(|matched| matched.end <= byte_idx)
+            .unwrap_or(false)
+        {
+            ranges.next();
+        }
+        if ranges
+            .peek()
+            .map(|matched| matched.start <= byte_idx)
+            .unwrap_or(false)
+        {
+            positions.push(char_idx);
+        }
+    }
+
+    positions
+}
@@ -0,0 +1,342 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use crossterm::event::KeyCode;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileManagerActions},
+        file_system::{
+            hex::{hex_line, parse_offset, read_chunk, BYTES_PER_LINE},
+            FileSystem,
+        },
+        state::AppState,
+    },
+    core::{
+        events::Event,
+        key_binding::KeyBinding,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+const MODAL_SIZE: u16 = 95;
+// Borders and status line
+const FRAME_HEIGHT: usize = 3;
+
+#[derive(Clone)]
+pub struct HexViewerModalComponentProps {
+    path: PathBuf,
+    file_size: u64,
+    modal_style: ModalStyle,
+    offset_color: Color,
+    go_to_key: KeyBinding,
+}
+
+impl HexViewerModalComponentProps {
+    pub fn new(
+        path: PathBuf,
+        file_size: u64,
+        modal_style: ModalStyle,
+        offset_color: Color,
+        go_to_key: KeyBinding,
+    ) -> Self {
+        HexViewerModalComponentProps {
+            path,
+            file_size,
+            modal_style,
+            offset_color,
+            go_to_key,
+        }
+    }
+}
+
+impl Default for HexViewerModalComponentProps {
+    fn default() -> Self {
+        HexViewerModalComponentProps {
+            path: PathBuf::new(),
+            file_size: 0,
+            modal_style: ModalStyle::default(),
+            offset_color: Color::Gray,
+            go_to_key: KeyBinding::new(KeyCode::Null),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct HexViewerModalComponentState {
+    // Offset of first displayed line, always multiple of line length
+    offset: u64,
+    // Byte jumped to with go to offset prompt
+    marked: Option<u64>,
+    prompt: Option<String>,
+    error: Option<String>,
+}
+
+pub struct HexViewerModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<HexViewerModalComponentProps, HexViewerModalComponentState>,
+    // Bytes displayed from current offset, only visible part of file is read
+    page: Vec<u8>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> HexViewerModalComponent<TFileSystem> {
+    pub fn new(props: HexViewerModalComponentProps) -> Self {
+        let mut component = HexViewerModalComponent {
+            base: ComponentBase::new(Some(props), Some(HexViewerModalComponentState::default())),
+            page: Vec::new(),
+            _marker: PhantomData,
+        };
+        component.load_page();
+
+        component
+    }
+
+    // Lines fitting on screen, layout is the same as in render
+    fn visible_lines() -> usize {
+        let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+        let area = create_modal_layout(MODAL_SIZE, MODAL_SIZE, Rect::new(0, 0, width, height));
+        (area.height as usize).saturating_sub(FRAME_HEIGHT).max(1)
+    }
+
+    fn last_offset(&self) -> u64 {
+        let props = self.base.get_props().unwrap();
+        let last_line = props.file_size.saturating_sub(1) / BYTES_PER_LINE as u64;
+        let page_lines = Self::visible_lines() as u64;
+        last_line.saturating_sub(page_lines - 1) * BYTES_PER_LINE as u64
+    }
+
+    fn load_page(&mut self) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        let length = (Self::visible_lines() + 1) * BYTES_PER_LINE;
+        match read_chunk(&props.path, local_state.offset, length) {
+            Ok(page) => {
+                self.page = page;
+                self.base
+                    .set_state(|current_state| HexViewerModalComponentState {
+                        error: None,
+                        ..current_state
+                    });
+            }
+            Err(err) => {
+                self.page = Vec::new();
+                self.base
+                    .set_state(|current_state| HexViewerModalComponentState {
+                        error: Some(err.to_string()),
+                        ..current_state
+                    });
+            }
+        }
+    }
+
+    fn scroll_to(&mut self, offset: u64, marked: Option<u64>) {
+        let line_offset = offset / BYTES_PER_LINE as u64 * BYTES_PER_LINE as u64;
+        let offset = line_offset.min(self.last_offset());
+        self.base
+            .set_state(|current_state| HexViewerModalComponentState {
+                offset,
+                marked,
+                ..current_state
+            });
+        self.load_page();
+    }
+
+    fn scroll_by_lines(&mut self, lines: i64) {
+        let local_state = self.base.get_state().unwrap();
+        let offset = local_state.offset as i64 + lines * BYTES_PER_LINE as i64;
+        self.scroll_to(offset.max(0) as u64, local_state.marked);
+    }
+
+    fn handle_prompt_key(&mut self, code: KeyCode, prompt: String) {
+        match code {
+            KeyCode::Enter => {
+                let props = self.base.get_props().unwrap();
+                match parse_offset(&prompt) {
+                    Some(offset) if offset < props.file_size => {
+                        self.set_prompt(None, None);
+                        self.scroll_to(offset, Some(offset));
+                    }
+                    Some(_) => self.set_prompt(
+                        Some(prompt),
+                        Some(format!(
+                            "Offset is past end of file ({} bytes)",
+                            props.file_size
+                        )),
+                    ),
+                    None => self.set_prompt(
+                        Some(prompt),
+                        Some("Offset has to be decimal or hexadecimal with 0x prefix".to_string()),
+                    ),
+                }
+            }
+            KeyCode::Char(c) => self.set_prompt(Some(format!("{}{}", prompt, c)), None),
+            KeyCode::Backspace => {
+                let mut prompt = prompt;
+                prompt.pop();
+                self.set_prompt(Some(prompt), None);
+            }
+            _ => {}
+        }
+    }
+
+    fn set_prompt(&mut self, prompt: Option<String>, error: Option<String>) {
+        self.base
+            .set_state(|current_state| HexViewerModalComponentState {
+                prompt: prompt.clone(),
+                error: error.clone(),
+                ..current_state
+            });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for HexViewerModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                if local_state.prompt.is_some() {
+                    self.set_prompt(None, None);
+                } else {
+                    store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                }
+                return true;
+            }
+
+            if let Some(prompt) = local_state.prompt {
+                self.handle_prompt_key(key_evt.code, prompt);
+                return true;
+            }
+
+            if state.config.keyboard_cfg.go_to_path.is_pressed(key_evt) {
+                self.set_prompt(Some(String::new()), None);
+                return true;
+            }
+
+            let page_lines = Self::visible_lines() as i64;
+            if state.config.keyboard_cfg.move_down.is_pressed(key_evt) {
+                self.scroll_by_lines(1);
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_up.is_pressed(key_evt) {
+                self.scroll_by_lines(-1);
+                return true;
+            }
+
+            match key_evt.code {
+                KeyCode::Down => self.scroll_by_lines(1),
+                KeyCode::Up => self.scroll_by_lines(-1),
+                KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by_lines(page_lines),
+                KeyCode::PageUp => self.scroll_by_lines(-page_lines),
+                KeyCode::Home => self.scroll_to(0, None),
+                KeyCode::End => self.scroll_to(u64::MAX, None),
+                _ => {}
+            };
+        }
+        true
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, _area: Option<Rect>) {
+        let layout = create_modal_layout(MODAL_SIZE, MODAL_SIZE, frame.size());
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+
+        let name = props
+            .path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(format!("Hex {} ({} bytes)", name, props.file_size)),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let inner = block.inner(layout);
+        let viewer_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+            .split(inner);
+
+        let offset_style = Style::default().fg(props.offset_color);
+        let marked_style = Style::default()
+            .bg(props.modal_style.selected_element_background)
+            .fg(props.modal_style.selected_element_foreground)
+            .add_modifier(Modifier::BOLD);
+        let lines: Vec<Spans> = self
+            .page
+            .chunks(BYTES_PER_LINE)
+            .enumerate()
+            .take(viewer_layout[0].height as usize)
+            .map(|(idx, line_bytes)| {
+                let line_offset = local_state.offset + (idx * BYTES_PER_LINE) as u64;
+                let (offset, hex, ascii) = hex_line(line_offset, line_bytes);
+                let mut spans = vec![Span::styled(offset, offset_style)];
+                match local_state.marked {
+                    Some(marked)
+                        if marked >= line_offset
+                            && marked < line_offset + BYTES_PER_LINE as u64 =>
+                    {
+                        // Every byte takes 3 characters and second half is moved by separator
+                        let column = (marked - line_offset) as usize;
+                        let start = column * 3 + column / (BYTES_PER_LINE / 2);
+                        spans.push(Span::from(hex[..start].to_string()));
+                        spans.push(Span::styled(
+                            hex[start..start + 2].to_string(),
+                            marked_style,
+                        ));
+                        spans.push(Span::from(hex[start + 2..].to_string()));
+                    }
+                    _ => spans.push(Span::from(hex)),
+                }
+                spans.push(Span::styled(
+                    ascii,
+                    Style::default().fg(props.modal_style.border_color),
+                ));
+                Spans::from(spans)
+            })
+            .collect();
+
+        let status = match (&local_state.prompt, &local_state.error) {
+            (_, Some(error)) => Span::styled(
+                error.clone(),
+                Style::default().fg(props.modal_style.selected_element_background),
+            ),
+            (Some(prompt), None) => Span::from(format!("Go to offset: {}", prompt)),
+            (None, None) => Span::styled(
+                format!(
+                    "0x{:08x} / 0x{:08x}  page up/down - page, {} - go to offset, esc - close",
+                    local_state.offset, props.file_size, props.go_to_key
+                ),
+                offset_style,
+            ),
+        };
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(block, layout);
+        frame.render_widget(Paragraph::new(lines), viewer_layout[0]);
+        frame.render_widget(Paragraph::new(Spans::from(status)), viewer_layout[1]);
+    }
+}
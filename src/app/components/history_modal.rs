@@ -0,0 +1,220 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileManagerActions, PanelSide, TabAction},
+        file_system::FileSystem,
+        state::{AppState, TabHistory, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+#[derive(Clone, Default)]
+pub struct HistoryModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    paths: Vec<PathBuf>,
+    current: usize,
+    list_selector: String,
+    modal_style: ModalStyle,
+}
+
+impl HistoryModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        current_path: PathBuf,
+        history: &TabHistory,
+        list_selector: String,
+        modal_style: ModalStyle,
+    ) -> Self {
+        // Oldest entry on top, then current directory and directories after it
+        let mut paths: Vec<PathBuf> = history
+            .back
+            .iter()
+            .map(|entry| entry.path.clone())
+            .collect();
+        let current = paths.len();
+        paths.push(current_path);
+        paths.extend(history.forward.iter().rev().map(|entry| entry.path.clone()));
+
+        HistoryModalComponentProps {
+            panel_side,
+            panel_tab,
+            paths,
+            current,
+            list_selector,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct HistoryModalComponentState {
+    list_state: ListState,
+}
+
+pub struct HistoryModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<HistoryModalComponentProps, HistoryModalComponentState>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> HistoryModalComponent<TFileSystem> {
+    pub fn new(props: HistoryModalComponentProps) -> Self {
+        let mut list_state = ListState::default();
+        list_state.select(Some(props.current));
+
+        HistoryModalComponent {
+            base: ComponentBase::new(Some(props), Some(HistoryModalComponentState { list_state })),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for HistoryModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        let paths_count = props.paths.len();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.move_up.is_pressed(key_evt) {
+                let next_item = match local_state.list_state.selected() {
+                    Some(current) => {
+                        if current == 0 {
+                            paths_count - 1
+                        } else {
+                            current - 1
+                        }
+                    }
+                    None => 0,
+                };
+                self.base.set_state(|mut current_state| {
+                    current_state.list_state.select(Some(next_item));
+                    HistoryModalComponentState {
+                        list_state: current_state.list_state,
+                    }
+                });
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_down.is_pressed(key_evt) {
+                let next_item = match local_state.list_state.selected() {
+                    Some(current) => {
+                        if current >= paths_count - 1 {
+                            0
+                        } else {
+                            current + 1
+                        }
+                    }
+                    None => 0,
+                };
+                self.base.set_state(|mut current_state| {
+                    current_state.list_state.select(Some(next_item));
+                    HistoryModalComponentState {
+                        list_state: current_state.list_state,
+                    }
+                });
+                return true;
+            }
+
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                if let Some(selected) = local_state.list_state.selected() {
+                    let panel_side = props.panel_side.unwrap();
+                    if selected < props.current {
+                        store.dispatch(FileManagerActions::Tab(TabAction::HistoryBack {
+                            panel_side,
+                            tab: props.panel_tab,
+                            steps: props.current - selected,
+                        }));
+                    } else if selected > props.current {
+                        store.dispatch(FileManagerActions::Tab(TabAction::HistoryForward {
+                            panel_side,
+                            tab: props.panel_tab,
+                            steps: selected - props.current,
+                        }));
+                    }
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+        }
+        false
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(80, 50, area)
+        } else {
+            create_modal_layout(80, 50, frame.size())
+        };
+
+        let props = self.base.get_props().unwrap();
+        let mut local_state = self.base.get_state().unwrap();
+
+        let items: Vec<ListItem> = props
+            .paths
+            .iter()
+            .enumerate()
+            .map(|(idx, path)| {
+                let span = Span::from(path.to_str().unwrap_or("").to_string());
+                if idx == props.current {
+                    ListItem::new(Spans::from(vec![span]))
+                        .style(Style::default().add_modifier(Modifier::BOLD))
+                } else {
+                    ListItem::new(Spans::from(vec![span]))
+                }
+            })
+            .collect();
+
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from("History"),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default().bg(tui::style::Color::Reset));
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(props.modal_style.selected_element_background)
+                    .fg(props.modal_style.selected_element_foreground),
+            )
+            .highlight_symbol(props.list_selector.as_str());
+
+        frame.render_widget(Clear, layout);
+        frame.render_stateful_widget(list, layout, &mut local_state.list_state);
+    }
+}
@@ -0,0 +1,240 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide},
+        file_system::FileSystem,
+        state::{AppState, TabIdx},
+    },
+    core::{
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{create_modal_layout, ModalStyle};
+
+const MAX_MATCHES: usize = 20;
+
+#[derive(Clone, Default)]
+pub struct JumpModalComponentProps {
+    panel_side: Option<PanelSide>,
+    panel_tab: TabIdx,
+    current_path: PathBuf,
+    list_selector: String,
+    modal_style: ModalStyle,
+}
+
+impl JumpModalComponentProps {
+    pub fn new(
+        panel_side: Option<PanelSide>,
+        panel_tab: TabIdx,
+        current_path: PathBuf,
+        list_selector: String,
+        modal_style: ModalStyle,
+    ) -> Self {
+        JumpModalComponentProps {
+            panel_side,
+            panel_tab,
+            current_path,
+            list_selector,
+            modal_style,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct JumpModalComponentState {
+    input: String,
+    matches: Vec<PathBuf>,
+    list_state: ListState,
+}
+
+pub struct JumpModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<JumpModalComponentProps, JumpModalComponentState>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> JumpModalComponent<TFileSystem> {
+    pub fn new(props: JumpModalComponentProps, matches: Vec<PathBuf>) -> Self {
+        let mut list_state = ListState::default();
+        if !matches.is_empty() {
+            list_state.select(Some(0));
+        }
+
+        JumpModalComponent {
+            base: ComponentBase::new(
+                Some(props),
+                Some(JumpModalComponentState {
+                    input: String::new(),
+                    matches,
+                    list_state,
+                }),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
+    fn update_input(
+        &mut self,
+        input: String,
+        store: &Store<AppState<TFileSystem>, FileManagerActions>,
+    ) {
+        let props = self.base.get_props().unwrap();
+        let matches =
+            store
+                .get_state()
+                .frecency
+                .query(&input, props.current_path.as_path(), MAX_MATCHES);
+        self.base.set_state(|mut current_state| {
+            current_state
+                .list_state
+                .select(if matches.is_empty() { None } else { Some(0) });
+            JumpModalComponentState {
+                input: input.clone(),
+                matches: matches.clone(),
+                ..current_state
+            }
+        });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for JumpModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        let props = self.base.get_props().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.accept.is_pressed(key_evt) {
+                let selected = local_state
+                    .list_state
+                    .selected()
+                    .and_then(|idx| local_state.matches.get(idx).cloned());
+                if let Some(path) = selected {
+                    store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                    store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
+                        panel: PanelInfo {
+                            side: props.panel_side.unwrap(),
+                            tab: props.panel_tab,
+                            path,
+                        },
+                        in_new_tab: false,
+                    }));
+                }
+                return true;
+            }
+
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            let matches_count = local_state.matches.len();
+            match key_evt.code {
+                KeyCode::Up | KeyCode::Down if matches_count > 0 => {
+                    let next_item = match (key_evt.code, local_state.list_state.selected()) {
+                        (KeyCode::Up, Some(0)) => matches_count - 1,
+                        (KeyCode::Up, Some(current)) => current - 1,
+                        (_, Some(current)) if current < matches_count - 1 => current + 1,
+                        _ => 0,
+                    };
+                    self.base.set_state(|mut current_state| {
+                        current_state.list_state.select(Some(next_item));
+                        current_state
+                    });
+                    return true;
+                }
+                KeyCode::Char(c) => {
+                    let mut input = local_state.input.clone();
+                    if key_evt.modifiers == KeyModifiers::SHIFT {
+                        input = format!("{}{}", input, c.to_uppercase());
+                    } else {
+                        input.push(c);
+                    }
+                    self.update_input(input, store);
+                    return true;
+                }
+                KeyCode::Backspace => {
+                    let mut input = local_state.input.clone();
+                    input.pop();
+                    self.update_input(input, store);
+                    return true;
+                }
+                _ => {}
+            };
+        }
+        false
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let layout = if let Some(area) = area {
+            create_modal_layout(80, 50, area)
+        } else {
+            create_modal_layout(80, 50, frame.size())
+        };
+        let modal_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(layout);
+
+        let props = self.base.get_props().unwrap();
+        let mut local_state = self.base.get_state().unwrap();
+
+        let input_block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from("Jump to:"),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(props.modal_style.border_color))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let paragraph = Paragraph::new(local_state.input.clone()).block(input_block);
+
+        let items: Vec<ListItem> = local_state
+            .matches
+            .iter()
+            .map(|path| {
+                ListItem::new(Spans::from(vec![Span::from(
+                    path.to_str().unwrap_or("").to_string(),
+                )]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(props.modal_style.border_color))
+                    .border_type(tui::widgets::BorderType::Thick),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(props.modal_style.selected_element_background)
+                    .fg(props.modal_style.selected_element_foreground),
+            )
+            .highlight_symbol(props.list_selector.as_str());
+
+        frame.render_widget(Clear, layout);
+        frame.render_widget(paragraph, modal_layout[0]);
+        frame.render_stateful_widget(list, modal_layout[1], &mut local_state.list_state);
+    }
+}
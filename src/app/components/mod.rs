@@ -3,13 +3,26 @@ use tui::{
     style::Color,
 };
 
+pub mod bookmarks_modal;
+pub mod connect_modal;
 pub mod create_modal;
+pub mod diff_modal;
 pub mod error_modal;
+pub mod find_modal;
+pub mod finder_modal;
+pub mod go_to_modal;
+pub mod grep_modal;
+pub mod hex_viewer_modal;
+pub mod history_modal;
+pub mod jump_modal;
 pub mod not_empty_dir_delete_modal;
 pub mod panel;
+pub mod parent_column;
+pub mod preview;
 pub mod rename_modal;
 pub mod root;
 pub mod tab;
+pub mod viewer_modal;
 
 fn create_modal_layout(x_percent: u16, y_percent: u16, rect: Rect) -> Rect {
     let vertical_slice = Layout::default()
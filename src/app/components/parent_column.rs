@@ -0,0 +1,162 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::FileManagerActions,
+        config::icon_cfg::IconsConfig,
+        file_system::{file_system_item::FileSystemItem, sftp_file_system::RemotePath, FileSystem},
+        state::AppState,
+    },
+    core::{
+        color_scheme::ColorScheme,
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+#[derive(Clone, Default)]
+pub struct ParentColumnComponentProps {
+    color_scheme: ColorScheme,
+}
+
+impl ParentColumnComponentProps {
+    pub fn new(color_scheme: ColorScheme) -> Self {
+        ParentColumnComponentProps { color_scheme }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ParentColumnComponentState {
+    // Directory shown in middle column, it is marked in parent listing
+    dir_path: Option<PathBuf>,
+    parent_path: Option<PathBuf>,
+    items: Vec<FileSystemItem>,
+}
+
+// Read only listing of parent directory of focused tab, navigation is done in focused tab
+pub struct ParentColumnComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<ParentColumnComponentProps, ParentColumnComponentState>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> ParentColumnComponent<TFileSystem> {
+    pub fn new(props: ParentColumnComponentProps) -> Self {
+        ParentColumnComponent {
+            base: ComponentBase::new(Some(props), Some(ParentColumnComponentState::default())),
+            _marker: PhantomData,
+        }
+    }
+
+    // Parent is listed again only when it changes, not on every event
+    pub fn show_dir(&mut self, dir_path: &Path, file_system: &TFileSystem, icons: &IconsConfig) {
+        let local_state = self.base.get_state().unwrap();
+        if local_state.dir_path.as_deref() == Some(dir_path) {
+            return;
+        }
+
+        // Path::parent would turn remote root sftp://host into sftp:
+        let parent_path = match RemotePath::parse(dir_path) {
+            Some(remote) => remote.parent().map(|parent| parent.to_path_buf()),
+            None => dir_path.parent().map(|parent| parent.to_path_buf()),
+        };
+        let items = match parent_path {
+            Some(ref parent_path) if local_state.parent_path.as_ref() == Some(parent_path) => {
+                local_state.items
+            }
+            Some(ref parent_path) => file_system.list_dir(parent_path, icons),
+            None => Vec::new(),
+        };
+        self.base.set_state(|_| ParentColumnComponentState {
+            dir_path: Some(dir_path.to_path_buf()),
+            parent_path: parent_path.clone(),
+            items: items.clone(),
+        });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for ParentColumnComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        _event: Event,
+        _store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        false
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let area = area.unwrap_or_else(|| frame.size());
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+
+        let name = local_state
+            .parent_path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or("/");
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(name),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+
+        let list_items: Vec<ListItem> = local_state
+            .items
+            .iter()
+            .map(|item| {
+                // Column is narrow so only names are shown, styled as in directory preview
+                let name = if item.is_dir() {
+                    Span::styled(
+                        format!(" {}/", item.get_name()),
+                        Style::default()
+                            .fg(props.color_scheme.normal_blue)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else if item.is_symlink() {
+                    Span::styled(
+                        format!(" {}", item.get_name()),
+                        Style::default().fg(props.color_scheme.normal_cyan),
+                    )
+                } else {
+                    Span::from(format!(" {}", item.get_name()))
+                };
+                ListItem::new(Spans::from(name))
+            })
+            .collect();
+        let mut list_state = ListState::default();
+        list_state.select(
+            local_state
+                .items
+                .iter()
+                .position(|item| Some(item.get_path()) == local_state.dir_path),
+        );
+        let list = List::new(list_items).block(block).highlight_style(
+            Style::default()
+                .bg(props.color_scheme.normal_red)
+                .fg(props.color_scheme.normal_black),
+        );
+
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
@@ -0,0 +1,345 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf, time::Duration};
+
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::FileManagerActions,
+        file_system::{
+            functions::format_size,
+            hex::{hex_line, BYTES_PER_LINE},
+            preview::{PreviewContent, PreviewLoader},
+            sftp_file_system::RemotePath,
+            FileSystem,
+        },
+        state::AppState,
+        syntax::TokenKind,
+    },
+    core::{
+        color_scheme::ColorScheme,
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+// How long tick waits for loader before content is shown on next tick
+const LOAD_WAIT: Duration = Duration::from_millis(30);
+const SCROLL_STEP: usize = 5;
+
+#[derive(Clone, Default)]
+pub struct PreviewComponentProps {
+    max_size_kb: u64,
+    syntax_highlighting: bool,
+    color_scheme: ColorScheme,
+}
+
+impl PreviewComponentProps {
+    pub fn new(max_size_kb: u64, syntax_highlighting: bool, color_scheme: ColorScheme) -> Self {
+        PreviewComponentProps {
+            max_size_kb,
+            syntax_highlighting,
+            color_scheme,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct PreviewComponentState {
+    path: Option<PathBuf>,
+    scroll: usize,
+}
+
+pub struct PreviewComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<PreviewComponentProps, PreviewComponentState>,
+    // Path of content which is loaded or being loaded
+    loaded_path: Option<PathBuf>,
+    content: Option<PreviewContent>,
+    loader: Option<PreviewLoader>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> PreviewComponent<TFileSystem> {
+    pub fn new(props: PreviewComponentProps) -> Self {
+        PreviewComponent {
+            base: ComponentBase::new(Some(props), Some(PreviewComponentState::default())),
+            loaded_path: None,
+            content: None,
+            loader: None,
+            _marker: PhantomData,
+        }
+    }
+
+    // Only remembers path, content is loaded on tick so holding move key
+    // does not start loading of every passed file
+    pub fn show_path(&mut self, path: Option<PathBuf>) {
+        let local_state = self.base.get_state().unwrap();
+        if local_state.path != path {
+            self.base.set_state(|_| PreviewComponentState {
+                path: path.clone(),
+                scroll: 0,
+            });
+        }
+    }
+
+    fn scroll_by(&mut self, offset: isize) {
+        let lines_count = match self.content {
+            Some(PreviewContent::Text { ref lines, .. }) => lines.len(),
+            Some(PreviewContent::Binary { ref bytes, .. }) => bytes.len().div_ceil(BYTES_PER_LINE),
+            Some(PreviewContent::Directory { ref entries, .. }) => entries.len(),
+            _ => 0,
+        };
+        self.base.set_state(|current_state| {
+            let scroll = (current_state.scroll as isize + offset)
+                .min(lines_count as isize - 1)
+                .max(0) as usize;
+            PreviewComponentState {
+                scroll,
+                ..current_state
+            }
+        });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions> for PreviewComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        if let Event::Keyboard(key_evt) = event {
+            if state
+                .config
+                .keyboard_cfg
+                .preview_scroll_down
+                .is_pressed(key_evt)
+            {
+                self.scroll_by(SCROLL_STEP as isize);
+                return true;
+            }
+
+            if state
+                .config
+                .keyboard_cfg
+                .preview_scroll_up
+                .is_pressed(key_evt)
+            {
+                self.scroll_by(-(SCROLL_STEP as isize));
+                return true;
+            }
+        }
+        false
+    }
+
+    fn on_tick(&mut self, _store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        if local_state.path != self.loaded_path {
+            self.loaded_path = local_state.path.clone();
+            self.loader = None;
+            self.content = match local_state.path {
+                Some(ref path) if RemotePath::parse(path).is_some() => Some(PreviewContent::Error(
+                    "Preview is available only for local files".to_string(),
+                )),
+                Some(path) => {
+                    self.loader = Some(PreviewLoader::start(
+                        path,
+                        props.max_size_kb * 1024,
+                        props.syntax_highlighting,
+                    ));
+                    None
+                }
+                None => None,
+            };
+        }
+
+        if let Some(ref loader) = self.loader {
+            if let Some(content) = loader.wait(LOAD_WAIT) {
+                self.content = Some(content);
+                self.loader = None;
+            }
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, area: Option<Rect>) {
+        let area = area.unwrap_or_else(|| frame.size());
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+
+        let name = local_state
+            .path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        let mut title = vec![Span::from("| "), Span::from(format!("Preview {}", name))];
+        if let Some(PreviewContent::Text {
+            truncated: true, ..
+        })
+        | Some(PreviewContent::Binary {
+            truncated: true, ..
+        }) = self.content
+        {
+            title.push(Span::from(format!(" [first {} KB]", props.max_size_kb)));
+        }
+        title.push(Span::from(" |"));
+        let block = Block::default()
+            .title(Spans::from(title))
+            .borders(Borders::ALL)
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let visible_lines = block.inner(area).height as usize;
+
+        let color_scheme = &props.color_scheme;
+        let message_style = Style::default().fg(color_scheme.normal_yellow);
+        let lines: Vec<Spans> = match (&self.content, &local_state.path) {
+            (_, None) => Vec::new(),
+            (None, Some(_)) => vec![Spans::from(Span::styled("Loading...", message_style))],
+            (
+                Some(PreviewContent::Text {
+                    lines, highlights, ..
+                }),
+                _,
+            ) => {
+                let number_width = lines.len().to_string().len();
+                let number_style = Style::default().fg(color_scheme.light_black);
+                lines
+                    .iter()
+                    .enumerate()
+                    .skip(local_state.scroll)
+                    .take(visible_lines)
+                    .map(|(idx, line)| {
+                        let mut spans = vec![Span::styled(
+                            format!("{:>width$} ", idx + 1, width = number_width),
+                            number_style,
+                        )];
+                        match highlights
+                            .as_ref()
+                            .and_then(|highlights| highlights.get(idx))
+                        {
+                            Some(line_highlight) => {
+                                spans.extend(line_highlight.iter().map(|(kind, range)| {
+                                    Span::styled(
+                                        &line[range.clone()],
+                                        Style::default().fg(token_color(*kind, color_scheme)),
+                                    )
+                                }))
+                            }
+                            None => spans.push(Span::from(line.as_str())),
+                        }
+                        Spans::from(spans)
+                    })
+                    .collect()
+            }
+            (Some(PreviewContent::Binary { bytes, .. }), _) => {
+                let offset_style = Style::default().fg(color_scheme.light_black);
+                bytes
+                    .chunks(BYTES_PER_LINE)
+                    .enumerate()
+                    .skip(local_state.scroll)
+                    .take(visible_lines)
+                    .map(|(idx, line_bytes)| {
+                        let (offset, hex, ascii) =
+                            hex_line((idx * BYTES_PER_LINE) as u64, line_bytes);
+                        Spans::from(vec![
+                            Span::styled(offset, offset_style),
+                            Span::from(hex),
+                            Span::styled(ascii, message_style),
+                        ])
+                    })
+                    .collect()
+            }
+            (
+                Some(PreviewContent::Directory {
+                    entries,
+                    dirs_count,
+                    files_count,
+                    total_size,
+                }),
+                _,
+            ) => {
+                let size_style = Style::default().fg(color_scheme.light_black);
+                // Summary stays on top while entries are scrolled
+                let mut lines = vec![Spans::from(Span::styled(
+                    format!(
+                        "{} items: {} directories, {} files, {}",
+                        dirs_count + files_count,
+                        dirs_count,
+                        files_count,
+                        format_size(*total_size)
+                    ),
+                    message_style,
+                ))];
+                lines.extend(
+                    entries
+                        .iter()
+                        .skip(local_state.scroll)
+                        .take(visible_lines.saturating_sub(1))
+                        .map(|entry| {
+                            let size = match entry.size {
+                                Some(size) => format_size(size),
+                                None => "-".to_string(),
+                            };
+                            let name = if entry.is_dir {
+                                Span::styled(
+                                    format!("{}/", entry.name),
+                                    Style::default()
+                                        .fg(color_scheme.normal_blue)
+                                        .add_modifier(Modifier::BOLD),
+                                )
+                            } else if entry.is_symlink {
+                                Span::styled(
+                                    entry.name.as_str(),
+                                    Style::default().fg(color_scheme.normal_cyan),
+                                )
+                            } else {
+                                Span::from(entry.name.as_str())
+                            };
+                            Spans::from(vec![
+                                Span::styled(format!("{:>6}  ", size), size_style),
+                                name,
+                            ])
+                        }),
+                );
+                let hidden_count = dirs_count + files_count - entries.len();
+                if hidden_count > 0 && local_state.scroll + visible_lines > entries.len() {
+                    lines.push(Spans::from(Span::styled(
+                        format!("... and {} more", hidden_count),
+                        message_style,
+                    )));
+                }
+                lines
+            }
+            (Some(PreviewContent::Error(error)), _) => {
+                vec![Spans::from(Span::styled(error.as_str(), message_style))]
+            }
+        };
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+fn token_color(kind: TokenKind, color_scheme: &ColorScheme) -> Color {
+    match kind {
+        TokenKind::Plain => color_scheme.foregorund,
+        TokenKind::Comment => color_scheme.light_black,
+        TokenKind::String => color_scheme.normal_green,
+        TokenKind::Number | TokenKind::Constant => color_scheme.light_red,
+        TokenKind::Keyword => color_scheme.normal_magneta,
+        TokenKind::Function => color_scheme.normal_blue,
+        TokenKind::Type => color_scheme.normal_yellow,
+        TokenKind::Heading => color_scheme.light_cyan,
+        TokenKind::Key => color_scheme.normal_cyan,
+        TokenKind::Variable => color_scheme.light_yellow,
+    }
+}
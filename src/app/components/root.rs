@@ -1,3 +1,4 @@
+use crossterm::event::KeyCode;
 use std::fmt::Debug;
 use tui::{
     backend::Backend,
@@ -6,9 +7,12 @@ use tui::{
 
 use crate::{
     app::{
-        actions::{AppAction, FileManagerActions, PanelSide},
+        actions::{
+            AppAction, BookmarkAction, DirectoryAction, FileManagerActions, PanelInfo, PanelSide,
+        },
+        config::{layout_cfg::SplitDirection, preview_cfg::PreviewPosition},
         file_system::FileSystem,
-        state::{AppState, ModalType},
+        state::{AppState, ModalType, PanelLayout},
     },
     core::{
         events::Event,
@@ -18,19 +22,56 @@ use crate::{
 };
 
 use super::{
+    bookmarks_modal::{BookmarksModalComponent, BookmarksModalComponentProps},
+    connect_modal::{ConnectModalComponent, ConnectModalComponentProps},
     create_modal::{CreateModalComponent, CreateModalProps},
+    diff_modal::{DiffModalComponent, DiffModalComponentProps},
     error_modal::{ErrorModalComponent, ErrorModalComponentProps},
+    find_modal::{FindModalComponent, FindModalComponentProps},
+    finder_modal::{FinderModalComponent, FinderModalComponentProps},
+    go_to_modal::{GoToModalComponent, GoToModalComponentProps},
+    grep_modal::{GrepModalComponent, GrepModalComponentProps},
+    hex_viewer_modal::{HexViewerModalComponent, HexViewerModalComponentProps},
+    history_modal::{HistoryModalComponent, HistoryModalComponentProps},
+    jump_modal::{JumpModalComponent, JumpModalComponentProps},
     not_empty_dir_delete_modal::{
         NotEmptyDirDeleteModalComponent, NotEmptyDirDeleteModalComponentProps,
     },
     panel::PanelComponent,
+    parent_column::{ParentColumnComponent, ParentColumnComponentProps},
+    preview::{PreviewComponent, PreviewComponentProps},
     rename_modal::{RenameModalComponent, RenameModalComponentProps},
+    viewer_modal::{ViewerModalComponent, ViewerModalComponentProps},
     ModalStyle,
 };
 
+// Percents by which panel grows or shrinks on key press
+const RESIZE_STEP: i16 = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+enum BookmarkKeyMode {
+    Mark,
+    Jump,
+}
+
 #[derive(Clone, Default)]
 pub struct RootComponentState {
     focused_panel: Option<PanelSide>,
+    bookmark_key_mode: Option<BookmarkKeyMode>,
+    preview_position: PreviewPosition,
+    layout: PanelLayout,
+    narrow_width: u16,
+}
+
+impl RootComponentState {
+    // Panels are placed above each other when configured or when terminal is too narrow
+    fn is_vertical(&self, area: Rect) -> bool {
+        match self.layout.split {
+            SplitDirection::Horizontal => false,
+            SplitDirection::Vertical => true,
+            SplitDirection::Auto => area.width < self.narrow_width,
+        }
+    }
 }
 
 pub struct RootComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
@@ -41,6 +82,19 @@ pub struct RootComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
     rename_modal: Option<RenameModalComponent<TFileSystem>>,
     error_modal: Option<ErrorModalComponent<TFileSystem>>,
     non_empty_dir_delete_modal: Option<NotEmptyDirDeleteModalComponent<TFileSystem>>,
+    connect_modal: Option<ConnectModalComponent<TFileSystem>>,
+    bookmarks_modal: Option<BookmarksModalComponent<TFileSystem>>,
+    history_modal: Option<HistoryModalComponent<TFileSystem>>,
+    jump_modal: Option<JumpModalComponent<TFileSystem>>,
+    go_to_modal: Option<GoToModalComponent<TFileSystem>>,
+    finder_modal: Option<FinderModalComponent<TFileSystem>>,
+    find_modal: Option<FindModalComponent<TFileSystem>>,
+    grep_modal: Option<GrepModalComponent<TFileSystem>>,
+    hex_viewer_modal: Option<HexViewerModalComponent<TFileSystem>>,
+    viewer_modal: Option<ViewerModalComponent<TFileSystem>>,
+    diff_modal: Option<DiffModalComponent<TFileSystem>>,
+    parent_column: Option<ParentColumnComponent<TFileSystem>>,
+    preview: Option<PreviewComponent<TFileSystem>>,
     _maker: std::marker::PhantomData<TFileSystem>,
 }
 
@@ -54,6 +108,19 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> RootComponent<TFileSyste
             rename_modal: None,
             error_modal: None,
             non_empty_dir_delete_modal: None,
+            connect_modal: None,
+            bookmarks_modal: None,
+            history_modal: None,
+            jump_modal: None,
+            go_to_modal: None,
+            finder_modal: None,
+            find_modal: None,
+            grep_modal: None,
+            hex_viewer_modal: None,
+            viewer_modal: None,
+            diff_modal: None,
+            parent_column: None,
+            preview: None,
             _maker: std::marker::PhantomData,
         }
     }
@@ -61,30 +128,76 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> RootComponent<TFileSyste
     fn map_state(&mut self, store: &Store<AppState<TFileSystem>, FileManagerActions>) {
         let state = store.get_state();
         if state.left_panel.is_focused {
-            self.base.set_state(|_current_state| RootComponentState {
+            self.base.set_state(|current_state| RootComponentState {
                 focused_panel: Some(PanelSide::Left),
+                ..current_state
             });
         } else if state.right_panel.is_focused {
-            self.base.set_state(|_current_state| RootComponentState {
+            self.base.set_state(|current_state| RootComponentState {
                 focused_panel: Some(PanelSide::Right),
+                ..current_state
             });
         } else {
-            self.base.set_state(|_current_state| RootComponentState {
+            self.base.set_state(|current_state| RootComponentState {
                 focused_panel: None,
+                ..current_state
             });
         }
         self.left_panel = PanelComponent::with_panel_state(
-            state.left_panel,
+            state.left_panel.clone(),
             PanelSide::Left,
             &state.config.icons,
             &state.config.core_cfg,
         );
         self.right_panel = PanelComponent::with_panel_state(
-            state.right_panel,
+            state.right_panel.clone(),
             PanelSide::Right,
             &state.config.icons,
             &state.config.core_cfg,
         );
+        self.base.set_state(|current_state| RootComponentState {
+            preview_position: state.config.preview.position,
+            layout: state.layout,
+            narrow_width: state.config.layout.narrow_width,
+            ..current_state
+        });
+        // Miller columns always show preview in third column
+        if state.preview_visible || state.miller_columns {
+            if self.preview.is_none() {
+                self.preview = Some(PreviewComponent::new(PreviewComponentProps::new(
+                    state.config.preview.max_size_kb,
+                    state.config.preview.syntax_highlighting,
+                    state.config.core_cfg.color_scheme,
+                )));
+            }
+            if let Some(ref mut preview) = self.preview {
+                preview.show_path(
+                    state
+                        .focused_tab()
+                        .current_item()
+                        .map(|item| item.get_path()),
+                );
+            }
+        } else {
+            self.preview = None;
+        }
+        if state.miller_columns {
+            if self.parent_column.is_none() {
+                self.parent_column = Some(ParentColumnComponent::new(
+                    ParentColumnComponentProps::new(state.config.core_cfg.color_scheme),
+                ));
+            }
+            if let Some(ref mut parent_column) = self.parent_column {
+                parent_column.show_dir(
+                    &state.focused_tab().path,
+                    &state.file_system,
+                    &state.config.icons,
+                );
+            }
+        } else {
+            self.parent_column = None;
+        }
+
         if let Some(modal_type) = state.modal.clone() {
             match modal_type {
                 ModalType::CreateModal {
@@ -166,6 +279,213 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> RootComponent<TFileSyste
                             ));
                     }
                 }
+                ModalType::BookmarksModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.bookmarks_modal.is_none() {
+                        self.bookmarks_modal = Some(BookmarksModalComponent::new(
+                            BookmarksModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                state.config.core_cfg.list_arrow.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            ),
+                            state.bookmarks.list(),
+                        ));
+                    }
+                }
+                ModalType::HistoryModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.history_modal.is_none() {
+                        let panel = match panel_side {
+                            PanelSide::Left => &state.left_panel,
+                            PanelSide::Right => &state.right_panel,
+                        };
+                        let tab_state = &panel.tabs[panel_tab];
+                        self.history_modal =
+                            Some(HistoryModalComponent::new(HistoryModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                tab_state.path.clone(),
+                                &tab_state.history,
+                                state.config.core_cfg.list_arrow.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            )));
+                    }
+                }
+                ModalType::JumpModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.jump_modal.is_none() {
+                        let current_path = state.focused_tab().path.clone();
+                        self.jump_modal = Some(JumpModalComponent::new(
+                            JumpModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                current_path.clone(),
+                                state.config.core_cfg.list_arrow.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            ),
+                            state.frecency.query("", current_path.as_path(), 20),
+                        ));
+                    }
+                }
+                ModalType::FinderModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.finder_modal.is_none() {
+                        self.finder_modal = Some(FinderModalComponent::new(
+                            FinderModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                state.focused_tab().path.clone(),
+                                state.config.search.show_hidden,
+                                state.config.core_cfg.list_arrow.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                                state.config.core_cfg.color_scheme.light_green,
+                            ),
+                            state.config.search.respect_gitignore,
+                        ));
+                    }
+                }
+                ModalType::GrepModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.grep_modal.is_none() {
+                        self.grep_modal = Some(GrepModalComponent::new(
+                            GrepModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                state.focused_tab().path.clone(),
+                                state.config.search.show_hidden,
+                                state.config.core_cfg.list_arrow.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                                state.config.core_cfg.color_scheme.light_green,
+                            ),
+                            state.config.search.respect_gitignore,
+                        ));
+                    }
+                }
+                ModalType::FindModal { panel_side } => {
+                    if self.find_modal.is_none() {
+                        self.find_modal = Some(FindModalComponent::new(
+                            FindModalComponentProps::new(
+                                Some(panel_side),
+                                state.focused_tab().path.clone(),
+                                state.config.search.show_hidden,
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            ),
+                            state.config.search.respect_gitignore,
+                        ));
+                    }
+                }
+                ModalType::HexViewerModal { path } => {
+                    if self.hex_viewer_modal.is_none() {
+                        let file_size = std::fs::metadata(&path)
+                            .map(|metadata| metadata.len())
+                            .unwrap_or(0);
+                        self.hex_viewer_modal = Some(HexViewerModalComponent::new(
+                            HexViewerModalComponentProps::new(
+                                path,
+                                file_size,
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                                state.config.core_cfg.color_scheme.light_black,
+                                state.config.keyboard_cfg.go_to_path,
+                            ),
+                        ));
+                    }
+                }
+                ModalType::ViewerModal { path } => {
+                    if self.viewer_modal.is_none() {
+                        self.viewer_modal =
+                            Some(ViewerModalComponent::new(ViewerModalComponentProps::new(
+                                path,
+                                state.config.core_cfg.color_scheme,
+                            )));
+                    }
+                }
+                ModalType::DiffModal { old_path, new_path } => {
+                    if self.diff_modal.is_none() {
+                        self.diff_modal = Some(DiffModalComponent::new(
+                            DiffModalComponentProps::new(
+                                old_path,
+                                new_path,
+                                state.config.core_cfg.color_scheme,
+                            ),
+                            &state.file_system,
+                        ));
+                    }
+                }
+                ModalType::GoToModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.go_to_modal.is_none() {
+                        self.go_to_modal =
+                            Some(GoToModalComponent::new(GoToModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                state.focused_tab().path.clone(),
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            )));
+                    }
+                }
+                ModalType::ConnectModal {
+                    panel_side,
+                    panel_tab,
+                } => {
+                    if self.connect_modal.is_none() {
+                        self.connect_modal = Some(ConnectModalComponent::with_props(
+                            ConnectModalComponentProps::new(
+                                Some(panel_side),
+                                panel_tab,
+                                ModalStyle::new(
+                                    state.config.core_cfg.color_scheme.normal_yellow,
+                                    state.config.core_cfg.color_scheme.light_cyan,
+                                    state.config.core_cfg.color_scheme.normal_black,
+                                ),
+                            ),
+                        ));
+                    }
+                }
             };
         }
         if self.create_modal.is_some() && state.modal.is_none() {
@@ -183,6 +503,50 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> RootComponent<TFileSyste
         if self.non_empty_dir_delete_modal.is_some() && state.modal.is_none() {
             self.non_empty_dir_delete_modal = None;
         }
+
+        if self.connect_modal.is_some() && state.modal.is_none() {
+            self.connect_modal = None;
+        }
+
+        if self.bookmarks_modal.is_some() && state.modal.is_none() {
+            self.bookmarks_modal = None;
+        }
+
+        if self.history_modal.is_some() && state.modal.is_none() {
+            self.history_modal = None;
+        }
+
+        if self.jump_modal.is_some() && state.modal.is_none() {
+            self.jump_modal = None;
+        }
+
+        if self.go_to_modal.is_some() && state.modal.is_none() {
+            self.go_to_modal = None;
+        }
+
+        if self.finder_modal.is_some() && state.modal.is_none() {
+            self.finder_modal = None;
+        }
+
+        if self.find_modal.is_some() && state.modal.is_none() {
+            self.find_modal = None;
+        }
+
+        if self.grep_modal.is_some() && state.modal.is_none() {
+            self.grep_modal = None;
+        }
+
+        if self.hex_viewer_modal.is_some() && state.modal.is_none() {
+            self.hex_viewer_modal = None;
+        }
+
+        if self.viewer_modal.is_some() && state.modal.is_none() {
+            self.viewer_modal = None;
+        }
+
+        if self.diff_modal.is_some() && state.modal.is_none() {
+            self.diff_modal = None;
+        }
     }
 }
 
@@ -192,6 +556,29 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
     fn on_tick(&mut self, store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
         self.left_panel.on_tick(store);
         self.right_panel.on_tick(store);
+        if let Some(ref mut finder_modal) = self.finder_modal {
+            finder_modal.on_tick(store);
+        }
+
+        if let Some(ref mut find_modal) = self.find_modal {
+            find_modal.on_tick(store);
+        }
+
+        if let Some(ref mut grep_modal) = self.grep_modal {
+            grep_modal.on_tick(store);
+        }
+
+        if let Some(ref mut preview) = self.preview {
+            preview.on_tick(store);
+        }
+
+        if let Some(ref mut viewer_modal) = self.viewer_modal {
+            viewer_modal.on_tick(store);
+        }
+
+        if let Some(ref mut diff_modal) = self.diff_modal {
+            diff_modal.on_tick(store);
+        }
 
         if store.is_dirty() {
             self.map_state(store);
@@ -219,6 +606,16 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     return true;
                 }
 
+                if state
+                    .config
+                    .keyboard_cfg
+                    .quit_without_changing_dir
+                    .is_pressed(key_evt)
+                {
+                    store.dispatch(FileManagerActions::App(AppAction::ExitWithoutChangingDir));
+                    return true;
+                }
+
                 if let Some(ref mut error_modal) = self.error_modal {
                     let result = error_modal.handle_event(event, store);
                     self.map_state(store);
@@ -250,6 +647,212 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     return result;
                 }
 
+                if let Some(ref mut connect_modal) = self.connect_modal {
+                    let result = connect_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut bookmarks_modal) = self.bookmarks_modal {
+                    let result = bookmarks_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut history_modal) = self.history_modal {
+                    let result = history_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut finder_modal) = self.finder_modal {
+                    let result = finder_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut find_modal) = self.find_modal {
+                    let result = find_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut grep_modal) = self.grep_modal {
+                    let result = grep_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut hex_viewer_modal) = self.hex_viewer_modal {
+                    let result = hex_viewer_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut viewer_modal) = self.viewer_modal {
+                    let result = viewer_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut diff_modal) = self.diff_modal {
+                    let result = diff_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut go_to_modal) = self.go_to_modal {
+                    let result = go_to_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                if let Some(ref mut jump_modal) = self.jump_modal {
+                    let result = jump_modal.handle_event(event, store);
+                    self.map_state(store);
+                    store.clean();
+
+                    return result;
+                }
+
+                let local_state = self.base.get_state().unwrap();
+                if let Some(bookmark_key_mode) = local_state.bookmark_key_mode {
+                    self.base.set_state(|current_state| RootComponentState {
+                        bookmark_key_mode: None,
+                        ..current_state
+                    });
+                    if let (KeyCode::Char(key), Some(panel_side)) =
+                        (key_evt.code, local_state.focused_panel)
+                    {
+                        let focused_tab = state.focused_tab();
+                        match bookmark_key_mode {
+                            BookmarkKeyMode::Mark => {
+                                store.dispatch(FileManagerActions::Bookmark(BookmarkAction::Add {
+                                    key,
+                                    path: focused_tab.path.clone(),
+                                }))
+                            }
+                            BookmarkKeyMode::Jump => match state.bookmarks.get(key) {
+                                Some(path) => store.dispatch(FileManagerActions::Directory(
+                                    DirectoryAction::Open {
+                                        panel: PanelInfo {
+                                            path,
+                                            tab: match panel_side {
+                                                PanelSide::Left => state.left_panel.current_tab,
+                                                PanelSide::Right => state.right_panel.current_tab,
+                                            },
+                                            side: panel_side,
+                                        },
+                                        in_new_tab: false,
+                                    },
+                                )),
+                                None => store.dispatch(FileManagerActions::App(
+                                    AppAction::ShowModal(ModalType::ErrorModal(format!(
+                                        "Bookmark '{}' is not set",
+                                        key
+                                    ))),
+                                )),
+                            },
+                        };
+                        self.map_state(store);
+                        store.clean();
+                    }
+
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.mark_bookmark.is_pressed(key_evt) {
+                    self.base.set_state(|current_state| RootComponentState {
+                        bookmark_key_mode: Some(BookmarkKeyMode::Mark),
+                        ..current_state
+                    });
+                    return true;
+                }
+
+                if state
+                    .config
+                    .keyboard_cfg
+                    .jump_to_bookmark
+                    .is_pressed(key_evt)
+                {
+                    self.base.set_state(|current_state| RootComponentState {
+                        bookmark_key_mode: Some(BookmarkKeyMode::Jump),
+                        ..current_state
+                    });
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.toggle_preview.is_pressed(key_evt) {
+                    store.dispatch(FileManagerActions::App(AppAction::TogglePreview));
+                    self.map_state(store);
+                    store.clean();
+
+                    return true;
+                }
+
+                if state
+                    .config
+                    .keyboard_cfg
+                    .toggle_miller_columns
+                    .is_pressed(key_evt)
+                {
+                    store.dispatch(FileManagerActions::App(AppAction::ToggleMillerColumns));
+                    self.map_state(store);
+                    store.clean();
+
+                    return true;
+                }
+
+                let layout_action = if state.config.keyboard_cfg.toggle_zoom.is_pressed(key_evt) {
+                    Some(AppAction::ToggleZoom)
+                } else if state
+                    .config
+                    .keyboard_cfg
+                    .cycle_split_direction
+                    .is_pressed(key_evt)
+                {
+                    Some(AppAction::CycleSplitDirection)
+                } else if state.config.keyboard_cfg.grow_panel.is_pressed(key_evt) {
+                    Some(AppAction::ResizePanels(RESIZE_STEP))
+                } else if state.config.keyboard_cfg.shrink_panel.is_pressed(key_evt) {
+                    Some(AppAction::ResizePanels(-RESIZE_STEP))
+                } else {
+                    None
+                };
+                if let Some(layout_action) = layout_action {
+                    store.dispatch(FileManagerActions::App(layout_action));
+                    self.map_state(store);
+                    store.clean();
+
+                    return true;
+                }
+
+                if let Some(ref mut preview) = self.preview {
+                    if preview.handle_event(event, store) {
+                        return true;
+                    }
+                }
+
                 if state
                     .config
                     .keyboard_cfg
@@ -294,12 +897,94 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
 
     fn render<TBackend: Backend>(&self, frame: &mut tui::Frame<TBackend>, _area: Option<Rect>) {
         let local_state = self.base.get_state().unwrap();
-        let layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(frame.size());
-        self.left_panel.render(frame, Some(layout[0]));
-        self.right_panel.render(frame, Some(layout[1]));
+        let is_vertical = local_state.is_vertical(frame.size());
+        let is_zoomed = local_state.layout.zoomed && local_state.focused_panel.is_some();
+        let mut layout = if is_zoomed {
+            vec![frame.size(), frame.size()]
+        } else {
+            Layout::default()
+                .direction(if is_vertical {
+                    Direction::Vertical
+                } else {
+                    Direction::Horizontal
+                })
+                .constraints([
+                    Constraint::Percentage(local_state.layout.ratio),
+                    Constraint::Percentage(100 - local_state.layout.ratio),
+                ])
+                .split(frame.size())
+        };
+        match (
+            &self.parent_column,
+            &self.preview,
+            local_state.focused_panel,
+        ) {
+            // Parent, current and preview columns with ratio as in ranger
+            (Some(parent_column), Some(preview), Some(focused_panel)) => {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(40),
+                        Constraint::Percentage(40),
+                    ])
+                    .split(frame.size());
+                parent_column.render(frame, Some(columns[0]));
+                match focused_panel {
+                    PanelSide::Left => self.left_panel.render(frame, Some(columns[1])),
+                    PanelSide::Right => self.right_panel.render(frame, Some(columns[1])),
+                };
+                preview.render(frame, Some(columns[2]));
+                // Modals of both panels are shown over current column
+                layout = vec![columns[1], columns[1]];
+            }
+            (_, Some(preview), Some(focused_panel)) => {
+                let (focused_area, inactive_area) = match focused_panel {
+                    PanelSide::Left => (layout[0], layout[1]),
+                    PanelSide::Right => (layout[1], layout[0]),
+                };
+                let focused = match focused_panel {
+                    PanelSide::Left => &self.left_panel,
+                    PanelSide::Right => &self.right_panel,
+                };
+                let inactive = match focused_panel {
+                    PanelSide::Left => &self.right_panel,
+                    PanelSide::Right => &self.left_panel,
+                };
+                match local_state.preview_position {
+                    PreviewPosition::InactivePanel if !is_zoomed => {
+                        focused.render(frame, Some(focused_area));
+                        preview.render(frame, Some(inactive_area));
+                    }
+                    // Zoomed panel has no inactive panel to take, so it is split as well
+                    _ => {
+                        let split = Layout::default()
+                            .direction(if is_vertical || is_zoomed {
+                                Direction::Horizontal
+                            } else {
+                                Direction::Vertical
+                            })
+                            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                            .split(focused_area);
+                        focused.render(frame, Some(split[0]));
+                        preview.render(frame, Some(split[1]));
+                        if !is_zoomed {
+                            inactive.render(frame, Some(inactive_area));
+                        }
+                    }
+                }
+            }
+            (_, _, Some(PanelSide::Left)) if is_zoomed => {
+                self.left_panel.render(frame, Some(layout[0]))
+            }
+            (_, _, Some(PanelSide::Right)) if is_zoomed => {
+                self.right_panel.render(frame, Some(layout[1]))
+            }
+            _ => {
+                self.left_panel.render(frame, Some(layout[0]));
+                self.right_panel.render(frame, Some(layout[1]));
+            }
+        }
         if let Some(ref create_modal) = self.create_modal {
             if let Some(focused_panel) = local_state.focused_panel.clone() {
                 match focused_panel {
@@ -322,6 +1007,107 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
             }
         }
 
+        if let Some(ref connect_modal) = self.connect_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => connect_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => connect_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                connect_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref bookmarks_modal) = self.bookmarks_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => bookmarks_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => bookmarks_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                bookmarks_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref history_modal) = self.history_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => history_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => history_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                history_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref finder_modal) = self.finder_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => finder_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => finder_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                finder_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref grep_modal) = self.grep_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => grep_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => grep_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                grep_modal.render(frame, None);
+            }
+        }
+
+        // Hex dump needs more width than single panel has
+        if let Some(ref hex_viewer_modal) = self.hex_viewer_modal {
+            hex_viewer_modal.render(frame, None);
+        }
+
+        if let Some(ref viewer_modal) = self.viewer_modal {
+            viewer_modal.render(frame, None);
+        }
+
+        if let Some(ref diff_modal) = self.diff_modal {
+            diff_modal.render(frame, None);
+        }
+
+        if let Some(ref find_modal) = self.find_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => find_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => find_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                find_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref go_to_modal) = self.go_to_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => go_to_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => go_to_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                go_to_modal.render(frame, None);
+            }
+        }
+
+        if let Some(ref jump_modal) = self.jump_modal {
+            if let Some(focused_panel) = local_state.focused_panel {
+                match focused_panel {
+                    PanelSide::Left => jump_modal.render(frame, Some(layout[0])),
+                    PanelSide::Right => jump_modal.render(frame, Some(layout[1])),
+                };
+            } else {
+                jump_modal.render(frame, None);
+            }
+        }
+
         if let Some(ref non_empty_dir_delete_modal) = self.non_empty_dir_delete_modal {
             if let Some(focused_panel) = local_state.focused_panel.clone() {
                 match focused_panel {
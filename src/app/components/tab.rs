@@ -1,5 +1,5 @@
 use crossterm::event::KeyCode;
-use std::fmt::Debug;
+use std::{fmt::Debug, path::PathBuf};
 use tui::{
     backend::Backend,
     layout::{Constraint, Layout, Rect},
@@ -16,8 +16,11 @@ use crate::{
             AppAction, DirectoryAction, FileAction, FileManagerActions, PanelInfo, PanelSide,
             SearchAction, SymlinkAction, TabAction,
         },
-        file_system::{file_system_item::FileSystemItem, FileSystem},
-        state::{AppState, ModalType, TabState},
+        file_system::{
+            file_system_item::FileSystemItem, sftp_file_system::RemotePath, viewer::is_text_file,
+            FileSystem,
+        },
+        state::{AppState, ModalType, PickerMode, TabState},
     },
     core::{
         events::Event,
@@ -74,6 +77,7 @@ pub struct TabStyle {
     active_border_color: Color,
     selected_element_background: Color,
     selected_element_foreground: Color,
+    error_color: Color,
 }
 
 impl Default for TabStyle {
@@ -82,6 +86,7 @@ impl Default for TabStyle {
             active_border_color: Color::Blue,
             selected_element_background: Color::Red,
             selected_element_foreground: Color::Black,
+            error_color: Color::Red,
         }
     }
 }
@@ -171,6 +176,22 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     return true;
                 }
 
+                if tab_state.search_mode
+                    && state
+                        .config
+                        .keyboard_cfg
+                        .switch_search_mode
+                        .is_pressed(key_evt)
+                {
+                    store.dispatch(FileManagerActions::Search(SearchAction::Input {
+                        tab: tab_idx,
+                        panel_side: tab_side,
+                        phrase: tab_state.phrase,
+                        mode: tab_state.search_filter.mode.next(),
+                    }));
+                    return true;
+                }
+
                 if tab_state.search_mode {
                     let mut phrase = tab_state.phrase;
                     match key_evt.code {
@@ -186,6 +207,7 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                         tab: tab_idx,
                         panel_side: tab_side,
                         phrase,
+                        mode: tab_state.search_filter.mode,
                     }));
                     return true;
                 }
@@ -233,10 +255,15 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
 
                 if state.config.keyboard_cfg.navigate_up.is_pressed(key_evt) && props.is_focused {
                     let current_path = tab_state.path;
-                    if let Some(parent) = current_path.parent() {
+                    // Path::parent would turn remote root sftp://host into sftp:
+                    let parent = match RemotePath::parse(&current_path) {
+                        Some(remote) => remote.parent().map(|parent| parent.to_path_buf()),
+                        None => current_path.parent().map(|parent| parent.to_path_buf()),
+                    };
+                    if let Some(parent) = parent {
                         store.dispatch(FileManagerActions::Directory(DirectoryAction::Open {
                             panel: PanelInfo {
-                                path: parent.into(),
+                                path: parent,
                                 tab: tab_idx,
                                 side: tab_side.clone(),
                             },
@@ -246,6 +273,44 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
 
                     return true;
                 }
+                if let Some(picker_mode) = state.picker_mode {
+                    // Without explicit selection item under cursor is chosen
+                    let chosen_items = if tab_state.selected.is_empty() {
+                        self.current_item().into_iter().collect()
+                    } else {
+                        tab_state.selected.clone()
+                    };
+                    let has_selected_file = chosen_items.iter().any(|item| item.is_file());
+                    if state.config.keyboard_cfg.accept.is_pressed(key_evt)
+                        || (state.config.keyboard_cfg.open.is_pressed(key_evt)
+                            && picker_mode == PickerMode::Files
+                            && has_selected_file)
+                    {
+                        let chosen_paths: Vec<_> = match picker_mode {
+                            PickerMode::Files => {
+                                chosen_items.iter().map(|item| item.get_path()).collect()
+                            }
+                            PickerMode::Directory => {
+                                let dirs: Vec<_> = chosen_items
+                                    .iter()
+                                    .filter(|item| item.is_dir())
+                                    .map(|item| item.get_path())
+                                    .collect();
+                                if dirs.is_empty() {
+                                    vec![tab_state.path.clone()]
+                                } else {
+                                    dirs
+                                }
+                            }
+                        };
+                        if !chosen_paths.is_empty() {
+                            store
+                                .dispatch(FileManagerActions::App(AppAction::Choose(chosen_paths)));
+                        }
+                        return true;
+                    }
+                }
+
                 if state.config.keyboard_cfg.open_as_tab.is_pressed(key_evt) && props.is_focused {
                     for item in tab_state.selected.iter() {
                         match item {
@@ -278,6 +343,39 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     return true;
                 }
 
+                if state
+                    .config
+                    .keyboard_cfg
+                    .toggle_expanded
+                    .is_pressed(key_evt)
+                    && props.is_focused
+                {
+                    // Search results are not directory listing so they can't be shown as tree
+                    match self.current_item() {
+                        Some(FileSystemItem::Directory(dir))
+                            if tab_state.search_results.is_none() =>
+                        {
+                            store.dispatch(FileManagerActions::Tab(TabAction::ToggleExpanded {
+                                panel_side: tab_side,
+                                tab: tab_idx,
+                                path: dir.get_path(),
+                            }));
+                        }
+                        _ => {}
+                    }
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.toggle_flat.is_pressed(key_evt) && props.is_focused {
+                    if tab_state.search_results.is_none() {
+                        store.dispatch(FileManagerActions::Tab(TabAction::ToggleFlat {
+                            panel_side: tab_side,
+                            tab: tab_idx,
+                        }));
+                    }
+                    return true;
+                }
+
                 if state.config.keyboard_cfg.open.is_pressed(key_evt) && props.is_focused {
                     for item in tab_state.selected.iter() {
                         match item {
@@ -605,6 +703,192 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     return true;
                 }
 
+                if state.config.keyboard_cfg.connect_remote.is_pressed(key_evt) {
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(
+                        ModalType::ConnectModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        },
+                    )));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.history_back.is_pressed(key_evt) {
+                    if !tab_state.history.back.is_empty() {
+                        store.dispatch(FileManagerActions::Tab(TabAction::HistoryBack {
+                            panel_side: tab_side,
+                            tab: tab_idx,
+                            steps: 1,
+                        }));
+                    }
+                    return true;
+                }
+
+                if state
+                    .config
+                    .keyboard_cfg
+                    .history_forward
+                    .is_pressed(key_evt)
+                {
+                    if !tab_state.history.forward.is_empty() {
+                        store.dispatch(FileManagerActions::Tab(TabAction::HistoryForward {
+                            panel_side: tab_side,
+                            tab: tab_idx,
+                            steps: 1,
+                        }));
+                    }
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.show_history.is_pressed(key_evt) {
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(
+                        ModalType::HistoryModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        },
+                    )));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.fuzzy_find.is_pressed(key_evt) {
+                    // Subtree is walked on background thread which works only with local disk
+                    let modal = if RemotePath::parse(&tab_state.path).is_some() {
+                        ModalType::ErrorModal(
+                            "Fuzzy finder works only with local directories".to_string(),
+                        )
+                    } else {
+                        ModalType::FinderModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        }
+                    };
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(modal)));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.grep.is_pressed(key_evt) {
+                    let modal = if RemotePath::parse(&tab_state.path).is_some() {
+                        ModalType::ErrorModal("Grep works only with local directories".to_string())
+                    } else {
+                        ModalType::GrepModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        }
+                    };
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(modal)));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.find.is_pressed(key_evt) {
+                    let modal = if RemotePath::parse(&tab_state.path).is_some() {
+                        ModalType::ErrorModal("Find works only with local directories".to_string())
+                    } else {
+                        ModalType::FindModal {
+                            panel_side: tab_side,
+                        }
+                    };
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(modal)));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.view.is_pressed(key_evt) {
+                    if let Some(current_item) = self.current_item() {
+                        let path = current_item.get_path();
+                        let modal = if RemotePath::parse(&path).is_some() {
+                            Some(ModalType::ErrorModal(
+                                "Viewer works only with local files".to_string(),
+                            ))
+                        } else if !path.is_file() {
+                            None
+                        } else if is_text_file(&path) {
+                            Some(ModalType::ViewerModal { path })
+                        } else {
+                            Some(ModalType::HexViewerModal { path })
+                        };
+                        if let Some(modal) = modal {
+                            store.dispatch(FileManagerActions::App(AppAction::ShowModal(modal)));
+                        }
+                    }
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.diff.is_pressed(key_evt) {
+                    // Two files selected in tab are compared, otherwise file under cursor
+                    // is compared with file under cursor in other panel
+                    let mut selected_files: Vec<PathBuf> = Vec::new();
+                    for item in tab_state.selected.iter().filter(|item| item.is_file()) {
+                        if !selected_files.contains(&item.get_path()) {
+                            selected_files.push(item.get_path());
+                        }
+                    }
+                    let paths = if selected_files.len() == 2 {
+                        Some((selected_files[0].clone(), selected_files[1].clone()))
+                    } else {
+                        let other_panel = match tab_side {
+                            PanelSide::Left => &state.right_panel,
+                            PanelSide::Right => &state.left_panel,
+                        };
+                        let other_item = other_panel.tabs[other_panel.current_tab]
+                            .current_item()
+                            .filter(|item| item.is_file())
+                            .map(|item| item.get_path());
+                        let current_item = self
+                            .current_item()
+                            .filter(|item| item.is_file())
+                            .map(|item| item.get_path());
+                        match (current_item, other_item, tab_side) {
+                            (Some(current), Some(other), PanelSide::Left) => Some((current, other)),
+                            (Some(current), Some(other), PanelSide::Right) => {
+                                Some((other, current))
+                            }
+                            _ => None,
+                        }
+                    };
+                    let modal = match paths {
+                        Some((old_path, new_path)) => ModalType::DiffModal { old_path, new_path },
+                        None => ModalType::ErrorModal(
+                            "Select two files in tab or file in each panel to compare".to_string(),
+                        ),
+                    };
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(modal)));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.go_to_path.is_pressed(key_evt) {
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(
+                        ModalType::GoToModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        },
+                    )));
+                    return true;
+                }
+
+                if state
+                    .config
+                    .keyboard_cfg
+                    .jump_to_directory
+                    .is_pressed(key_evt)
+                {
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(
+                        ModalType::JumpModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        },
+                    )));
+                    return true;
+                }
+
+                if state.config.keyboard_cfg.show_bookmarks.is_pressed(key_evt) {
+                    store.dispatch(FileManagerActions::App(AppAction::ShowModal(
+                        ModalType::BookmarksModal {
+                            panel_side: tab_side,
+                            panel_tab: tab_idx,
+                        },
+                    )));
+                    return true;
+                }
+
                 if tab_state.selected.len() == 1 || tab_state.tab_state.selected().is_none() {
                     if let Some(current_item) = self.current_item() {
                         if state.config.keyboard_cfg.rename.is_pressed(key_evt) && props.is_focused
@@ -659,62 +943,64 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     vec![area.unwrap()]
                 };
 
-                let list_items: Vec<ListItem> = if state.phrase.is_empty() {
-                    state
-                        .items
-                        .iter()
-                        .map(|item| {
-                            if state
-                                .selected
-                                .iter()
-                                .any(|i| i.get_path() == item.get_path())
+                let filtered_items: Vec<FileSystemItem> =
+                    state.filtered_items().into_iter().cloned().collect();
+                // Filtered items are not in tree order so guides are drawn only for full listing
+                let guides = if !state.expanded.is_empty() && state.phrase.is_empty() {
+                    tree_guides(&state, &filtered_items)
+                } else {
+                    Vec::new()
+                };
+                let list_items: Vec<ListItem> = filtered_items
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, item)| {
+                        let mut spans = item.to_spans(area.unwrap_or(frame.size()), show_icons);
+                        if let Some(guide) = guides.get(idx).filter(|guide| !guide.is_empty()) {
+                            let name = item.get_name();
+                            if let Some(span) = spans
+                                .0
+                                .iter_mut()
+                                .find(|span| span.content == name.as_str())
                             {
-                                ListItem::new(
-                                    item.to_spans(area.unwrap_or(frame.size()), show_icons),
-                                )
-                                .style(
-                                    Style::default()
-                                        .bg(self.style.selected_element_background)
-                                        .fg(self.style.selected_element_foreground),
-                                )
-                            } else {
-                                ListItem::new(
-                                    item.to_spans(area.unwrap_or(frame.size()), show_icons),
-                                )
+                                *span = Span::styled(format!("{}{}", guide, name), span.style);
                             }
-                        })
-                        .collect()
-                } else {
-                    state
-                        .items
-                        .iter()
-                        .filter(|item| {
-                            item.get_name()
-                                .to_lowercase()
-                                .contains(&state.phrase.to_lowercase())
-                        })
-                        .map(|item| {
-                            if state
-                                .selected
-                                .iter()
-                                .any(|i| i.get_path() == item.get_path())
+                        }
+                        // Search results and flat view come from whole subtree so path relative
+                        // to tab directory is shown instead of name
+                        if state.search_results.is_some() || state.flat_depth.is_some() {
+                            let name = item.get_name();
+                            let item_path = item.get_path();
+                            let relative_path = item_path
+                                .strip_prefix(&state.path)
+                                .ok()
+                                .and_then(|path| path.to_str())
+                                .unwrap_or(name.as_str())
+                                .to_string();
+                            if let Some(span) = spans
+                                .0
+                                .iter_mut()
+                                .find(|span| span.content == name.as_str())
                             {
-                                ListItem::new(
-                                    item.to_spans(area.unwrap_or(frame.size()), show_icons),
-                                )
-                                .style(
-                                    Style::default()
-                                        .bg(self.style.selected_element_background)
-                                        .fg(self.style.selected_element_foreground),
-                                )
-                            } else {
-                                ListItem::new(
-                                    item.to_spans(area.unwrap_or(frame.size()), show_icons),
-                                )
+                                *span = Span::from(relative_path);
                             }
-                        })
-                        .collect()
-                };
+                        }
+
+                        if state
+                            .selected
+                            .iter()
+                            .any(|i| i.get_path() == item.get_path())
+                        {
+                            ListItem::new(spans).style(
+                                Style::default()
+                                    .bg(self.style.selected_element_background)
+                                    .fg(self.style.selected_element_foreground),
+                            )
+                        } else {
+                            ListItem::new(spans)
+                        }
+                    })
+                    .collect();
 
                 let border_style = if tab_props.is_focused {
                     Style::default().fg(self.style.active_border_color)
@@ -722,21 +1008,21 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                     Style::default()
                 };
 
+                let name = match state.flat_depth {
+                    Some(depth) => format!("{} [flat {}]", state.name, depth),
+                    None => state.name.clone(),
+                };
                 let block = Block::default()
                     .title(if show_icons {
                         Spans::from(vec![
                             Span::from("| "),
                             Span::from(state.icon),
                             Span::from(" "),
-                            Span::from(state.name),
+                            Span::from(name),
                             Span::from(" |"),
                         ])
                     } else {
-                        Spans::from(vec![
-                            Span::from("| "),
-                            Span::from(state.name),
-                            Span::from(" |"),
-                        ])
+                        Spans::from(vec![Span::from("| "), Span::from(name), Span::from(" |")])
                     })
                     .borders(Borders::ALL)
                     .border_style(border_style)
@@ -760,12 +1046,20 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
                 }
 
                 if (state.search_mode || state.phrase.is_empty() == false) && tab_props.is_focused {
+                    let mut title = vec![
+                        Span::from("| "),
+                        Span::from(format!("Search [{}]", state.search_filter.mode.name())),
+                        Span::from(" |"),
+                    ];
+                    if let Some(ref error) = state.search_filter.error {
+                        title.push(Span::styled(
+                            format!(" {} ", error),
+                            Style::default().fg(self.style.error_color),
+                        ));
+                        title.push(Span::from("|"));
+                    }
                     let block = Block::default()
-                        .title(Spans::from(vec![
-                            Span::from("| "),
-                            Span::from("Search"),
-                            Span::from(" |"),
-                        ]))
+                        .title(Spans::from(title))
                         .borders(Borders::ALL)
                         .border_style(border_style)
                         .border_type(tui::widgets::BorderType::Thick)
@@ -779,3 +1073,31 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem>
         }
     }
 }
+
+// Indentation guides drawn before names of items listed inside expanded directories,
+// item is last child when no sibling follows it before listing goes back to lower level
+fn tree_guides<TFileSystem: Clone + Debug + Default + FileSystem>(
+    state: &TabState<TFileSystem>,
+    items: &[FileSystemItem],
+) -> Vec<String> {
+    let depths: Vec<usize> = items.iter().map(|item| state.depth(item)).collect();
+    let mut has_sibling_after: Vec<bool> = Vec::new();
+    let mut guides = vec![String::new(); items.len()];
+    for (idx, depth) in depths.iter().enumerate().rev() {
+        has_sibling_after.resize(depth + 1, false);
+        let is_last = !has_sibling_after[*depth];
+        has_sibling_after[*depth] = true;
+        if *depth == 0 {
+            continue;
+        }
+
+        let mut guide: String = has_sibling_after[1..*depth]
+            .iter()
+            .map(|has_sibling| if *has_sibling { "│  " } else { "   " })
+            .collect();
+        guide.push_str(if is_last { "└─ " } else { "├─ " });
+        guides[idx] = guide;
+    }
+
+    guides
+}
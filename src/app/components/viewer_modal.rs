@@ -0,0 +1,505 @@
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use regex::{Regex, RegexBuilder};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{
+        actions::{AppAction, FileManagerActions},
+        file_system::{
+            viewer::{ViewerDocument, ViewerFollower, ViewerLoader, MAX_VIEW_SIZE},
+            FileSystem,
+        },
+        search_filter::regex_error_message,
+        state::AppState,
+    },
+    core::{
+        color_scheme::ColorScheme,
+        events::Event,
+        store::Store,
+        ui::{component::Component, component_base::ComponentBase},
+    },
+};
+
+use super::{finder_modal::highlight_positions, grep_modal::match_positions};
+
+// Borders and status line
+const FRAME_HEIGHT: usize = 3;
+const HORIZONTAL_STEP: usize = 8;
+
+#[derive(Clone, Default)]
+pub struct ViewerModalComponentProps {
+    path: PathBuf,
+    color_scheme: ColorScheme,
+}
+
+impl ViewerModalComponentProps {
+    pub fn new(path: PathBuf, color_scheme: ColorScheme) -> Self {
+        ViewerModalComponentProps { path, color_scheme }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ViewerModalComponentState {
+    // First displayed line
+    scroll: usize,
+    // First displayed column when lines are not wrapped
+    column: usize,
+    wrap: bool,
+    follow: bool,
+    prompt: Option<String>,
+    message: Option<String>,
+}
+
+pub struct ViewerModalComponent<TFileSystem: Clone + Debug + Default + FileSystem> {
+    base: ComponentBase<ViewerModalComponentProps, ViewerModalComponentState>,
+    document: Option<ViewerDocument>,
+    loader: Option<ViewerLoader>,
+    follower: Option<ViewerFollower>,
+    pattern: Option<Regex>,
+    _marker: PhantomData<TFileSystem>,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> ViewerModalComponent<TFileSystem> {
+    pub fn new(props: ViewerModalComponentProps) -> Self {
+        let loader = ViewerLoader::start(props.path.clone(), false);
+        ViewerModalComponent {
+            base: ComponentBase::new(Some(props), Some(ViewerModalComponentState::default())),
+            document: None,
+            loader: Some(loader),
+            follower: None,
+            pattern: None,
+            _marker: PhantomData,
+        }
+    }
+
+    // Viewer takes whole screen, layout is the same as in render
+    fn visible_lines() -> usize {
+        let (_, height) = crossterm::terminal::size().unwrap_or((80, 24));
+        (height as usize).saturating_sub(FRAME_HEIGHT).max(1)
+    }
+
+    fn lines_count(&self) -> usize {
+        self.document
+            .as_ref()
+            .map(|document| document.lines.len())
+            .unwrap_or(0)
+    }
+
+    fn last_scroll(&self) -> usize {
+        self.lines_count().saturating_sub(Self::visible_lines())
+    }
+
+    fn scroll_to(&mut self, line: usize) {
+        let scroll = line.min(self.last_scroll());
+        self.base
+            .set_state(|current_state| ViewerModalComponentState {
+                scroll,
+                ..current_state
+            });
+    }
+
+    fn scroll_by(&mut self, lines: isize) {
+        let local_state = self.base.get_state().unwrap();
+        self.scroll_to((local_state.scroll as isize + lines).max(0) as usize);
+    }
+
+    fn set_message(&mut self, message: Option<String>) {
+        self.base
+            .set_state(|current_state| ViewerModalComponentState {
+                message: message.clone(),
+                ..current_state
+            });
+    }
+
+    fn toggle_follow(&mut self) {
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        let follow = !local_state.follow;
+        self.base
+            .set_state(|current_state| ViewerModalComponentState {
+                follow,
+                ..current_state
+            });
+        if follow {
+            // Beginning of big file is replaced with its end which is followed
+            if self.document.as_ref().map(|document| document.truncated) == Some(true) {
+                self.document = None;
+                self.loader = Some(ViewerLoader::start(props.path, true));
+            } else {
+                self.start_follower();
+            }
+            let last_scroll = self.last_scroll();
+            self.scroll_to(last_scroll);
+        } else {
+            self.follower = None;
+        }
+    }
+
+    fn start_follower(&mut self) {
+        let props = self.base.get_props().unwrap();
+        if let Some(ref mut document) = self.document {
+            self.follower = Some(ViewerFollower::start(props.path, document));
+        }
+    }
+
+    fn start_search(&mut self, input: String) {
+        // Smart case as in panel search
+        let case_insensitive = !input.chars().any(|c| c.is_uppercase());
+        match RegexBuilder::new(&input)
+            .case_insensitive(case_insensitive)
+            .build()
+        {
+            Ok(pattern) => {
+                self.pattern = Some(pattern);
+                self.base
+                    .set_state(|current_state| ViewerModalComponentState {
+                        prompt: None,
+                        ..current_state
+                    });
+                let local_state = self.base.get_state().unwrap();
+                self.find_match(local_state.scroll, true);
+            }
+            Err(err) => self.set_message(Some(regex_error_message(&err))),
+        }
+    }
+
+    // Searches from given line in given direction wrapping around file
+    fn find_match(&mut self, from: usize, forward: bool) {
+        let found = match (&self.document, &self.pattern) {
+            (Some(document), Some(pattern)) => {
+                let count = document.lines.len();
+                (0..count)
+                    .map(|step| {
+                        if forward {
+                            (from + step) % count
+                        } else {
+                            (from + count - step % count) % count
+                        }
+                    })
+                    .find(|idx| pattern.is_match(&document.lines[*idx]))
+            }
+            _ => return,
+        };
+
+        match found {
+            Some(line) => {
+                self.set_message(None);
+                // Found line is shown as first one, unless it is close to the end
+                self.follower = None;
+                self.base
+                    .set_state(|current_state| ViewerModalComponentState {
+                        follow: false,
+                        ..current_state
+                    });
+                self.scroll_to(line);
+            }
+            None => self.set_message(Some("Pattern not found".to_string())),
+        }
+    }
+
+    fn handle_prompt_key(&mut self, code: KeyCode, modifiers: KeyModifiers, prompt: String) {
+        let mut prompt = prompt;
+        match code {
+            KeyCode::Enter => {
+                if !prompt.is_empty() {
+                    self.start_search(prompt);
+                }
+                return;
+            }
+            KeyCode::Char(c) => {
+                if modifiers == KeyModifiers::SHIFT {
+                    prompt.push_str(&c.to_uppercase().to_string());
+                } else {
+                    prompt.push(c);
+                }
+            }
+            KeyCode::Backspace => {
+                prompt.pop();
+            }
+            _ => return,
+        };
+        self.base
+            .set_state(|current_state| ViewerModalComponentState {
+                prompt: Some(prompt.clone()),
+                message: None,
+                ..current_state
+            });
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem>
+    Component<Event, AppState<TFileSystem>, FileManagerActions>
+    for ViewerModalComponent<TFileSystem>
+{
+    fn handle_event(
+        &mut self,
+        event: Event,
+        store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    ) -> bool {
+        let state = store.get_state();
+        let local_state = self.base.get_state().unwrap();
+        if let Event::Keyboard(key_evt) = event {
+            if state.config.keyboard_cfg.close.is_pressed(key_evt) {
+                if local_state.prompt.is_some() {
+                    self.base
+                        .set_state(|current_state| ViewerModalComponentState {
+                            prompt: None,
+                            message: None,
+                            ..current_state
+                        });
+                } else {
+                    store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                }
+                return true;
+            }
+
+            if let Some(prompt) = local_state.prompt {
+                self.handle_prompt_key(key_evt.code, key_evt.modifiers, prompt);
+                return true;
+            }
+
+            if state.config.keyboard_cfg.view.is_pressed(key_evt) {
+                store.dispatch(FileManagerActions::App(AppAction::CloseModal));
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_down.is_pressed(key_evt) {
+                self.scroll_by(1);
+                return true;
+            }
+
+            if state.config.keyboard_cfg.move_up.is_pressed(key_evt) {
+                self.scroll_by(-1);
+                return true;
+            }
+
+            let page_lines = Self::visible_lines() as isize;
+            match key_evt.code {
+                KeyCode::Down => self.scroll_by(1),
+                KeyCode::Up => self.scroll_by(-1),
+                KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(page_lines),
+                KeyCode::PageUp => self.scroll_by(-page_lines),
+                KeyCode::Home | KeyCode::Char('g') => self.scroll_to(0),
+                KeyCode::End | KeyCode::Char('G') => self.scroll_to(usize::MAX),
+                KeyCode::Left => self
+                    .base
+                    .set_state(|current_state| ViewerModalComponentState {
+                        column: current_state.column.saturating_sub(HORIZONTAL_STEP),
+                        ..current_state
+                    }),
+                KeyCode::Right => self
+                    .base
+                    .set_state(|current_state| ViewerModalComponentState {
+                        column: current_state.column + HORIZONTAL_STEP,
+                        ..current_state
+                    }),
+                KeyCode::Char('/') => {
+                    self.base
+                        .set_state(|current_state| ViewerModalComponentState {
+                            prompt: Some(String::new()),
+                            message: None,
+                            ..current_state
+                        })
+                }
+                KeyCode::Char('n') => self.find_match(local_state.scroll + 1, true),
+                KeyCode::Char('N') => {
+                    let count = self.lines_count().max(1);
+                    self.find_match((local_state.scroll + count - 1) % count, false)
+                }
+                KeyCode::Char('w') => {
+                    self.base
+                        .set_state(|current_state| ViewerModalComponentState {
+                            wrap: !current_state.wrap,
+                            column: 0,
+                            ..current_state
+                        })
+                }
+                KeyCode::Char('f') => self.toggle_follow(),
+                _ => {}
+            };
+        }
+        true
+    }
+
+    fn on_tick(&mut self, _store: &mut Store<AppState<TFileSystem>, FileManagerActions>) {
+        let local_state = self.base.get_state().unwrap();
+        if let Some(ref loader) = self.loader {
+            match loader.poll() {
+                Some(Ok(document)) => {
+                    self.document = Some(document);
+                    self.loader = None;
+                    if local_state.follow {
+                        self.start_follower();
+                        let last_scroll = self.last_scroll();
+                        self.scroll_to(last_scroll);
+                    }
+                }
+                Some(Err(err)) => {
+                    self.loader = None;
+                    self.set_message(Some(err.to_string()));
+                }
+                None => {}
+            }
+            return;
+        }
+
+        let update = match self.follower {
+            Some(ref follower) => follower.poll(),
+            None => None,
+        };
+        match update {
+            Some(Ok(update)) => {
+                if let Some(ref mut document) = self.document {
+                    document.apply(update);
+                }
+                let last_scroll = self.last_scroll();
+                self.scroll_to(last_scroll);
+            }
+            Some(Err(err)) => {
+                self.follower = None;
+                self.base
+                    .set_state(|current_state| ViewerModalComponentState {
+                        follow: false,
+                        ..current_state
+                    });
+                self.set_message(Some(err.to_string()));
+            }
+            None => {}
+        }
+    }
+
+    fn render<TBackend: Backend>(&self, frame: &mut Frame<TBackend>, _area: Option<Rect>) {
+        let area = frame.size();
+        let props = self.base.get_props().unwrap();
+        let local_state = self.base.get_state().unwrap();
+        let color_scheme = &props.color_scheme;
+
+        let mut title = format!("View {}", props.path.to_str().unwrap_or(""));
+        if let Some(ref document) = self.document {
+            title.push_str(&format!(" [{}]", document.encoding.name()));
+            if document.truncated {
+                title.push_str(&format!(
+                    " [{} {} MB]",
+                    if local_state.follow { "last" } else { "first" },
+                    MAX_VIEW_SIZE / 1024 / 1024
+                ));
+            }
+        }
+        if local_state.wrap {
+            title.push_str(" [wrap]");
+        }
+        if local_state.follow {
+            title.push_str(" [follow]");
+        }
+        let block = Block::default()
+            .title(Spans::from(vec![
+                Span::from("| "),
+                Span::from(title),
+                Span::from(" |"),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color_scheme.normal_yellow))
+            .border_type(tui::widgets::BorderType::Thick)
+            .style(Style::default());
+        let inner = block.inner(area);
+        let viewer_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+            .split(inner);
+
+        let number_style = Style::default().fg(color_scheme.light_black);
+        let highlight_style = Style::default()
+            .fg(color_scheme.normal_black)
+            .bg(color_scheme.normal_yellow)
+            .add_modifier(Modifier::BOLD);
+        let lines: Vec<Spans> = match self.document {
+            Some(ref document) => {
+                let number_width = document.lines.len().to_string().len();
+                // Wrapped line is shown on all rows at most, single line file can be huge
+                let text_width = viewer_layout[0].width as usize;
+                let visible_chars = if local_state.wrap {
+                    text_width * viewer_layout[0].height as usize
+                } else {
+                    text_width
+                };
+                document
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .skip(local_state.scroll)
+                    .take(viewer_layout[0].height as usize)
+                    .map(|(idx, line)| {
+                        let column = if local_state.wrap {
+                            0
+                        } else {
+                            local_state.column
+                        };
+                        let start = line
+                            .char_indices()
+                            .nth(column)
+                            .map(|(byte_idx, _)| byte_idx)
+                            .unwrap_or(line.len());
+                        let end = line[start..]
+                            .char_indices()
+                            .nth(visible_chars)
+                            .map(|(byte_idx, _)| start + byte_idx)
+                            .unwrap_or(line.len());
+                        let visible = &line[start..end];
+                        let positions: Vec<usize> = match self.pattern {
+                            Some(ref pattern) => match_positions(pattern, line, start..end),
+                            None => Vec::new(),
+                        };
+                        let mut spans = vec![Span::styled(
+                            format!("{:>width$} ", idx + 1, width = number_width),
+                            number_style,
+                        )];
+                        spans.extend(
+                            highlight_positions(visible, &positions, highlight_style)
+                                .into_iter()
+                                .map(|span| Span::styled(span.content.into_owned(), span.style)),
+                        );
+                        Spans::from(spans)
+                    })
+                    .collect()
+            }
+            None => vec![Spans::from(Span::styled(
+                "Loading...",
+                Style::default().fg(color_scheme.normal_yellow),
+            ))],
+        };
+        let mut paragraph = Paragraph::new(lines);
+        if local_state.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
+
+        let status = match (&local_state.prompt, &local_state.message) {
+            (_, Some(message)) => Span::styled(
+                message.clone(),
+                Style::default().fg(color_scheme.normal_red),
+            ),
+            (Some(prompt), None) => Span::from(format!("/{}", prompt)),
+            (None, None) => Span::styled(
+                format!(
+                    "{}/{}  / - search, n/N - next/previous, w - wrap, f - follow, esc - close",
+                    (local_state.scroll + 1).min(self.lines_count()),
+                    self.lines_count()
+                ),
+                number_style,
+            ),
+        };
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        frame.render_widget(paragraph, viewer_layout[0]);
+        frame.render_widget(Paragraph::new(Spans::from(status)), viewer_layout[1]);
+    }
+}
@@ -0,0 +1,32 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use toml::Value;
+
+use crate::app::file_system::functions::expand_if_contains_tilde;
+
+#[derive(Debug, Clone, Default)]
+pub struct BookmarksConfig {
+    pub bookmarks: BTreeMap<char, PathBuf>,
+}
+
+impl BookmarksConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        self.bookmarks.extend(parse_bookmarks(cfg));
+    }
+}
+
+pub fn parse_bookmarks(cfg: &Value) -> BTreeMap<char, PathBuf> {
+    let mut result = BTreeMap::new();
+    if let Some(Value::Table(bookmarks)) = cfg.get("bookmarks") {
+        for (key, path) in bookmarks.iter() {
+            let mut key_chars = key.chars();
+            if let (Some(key), None) = (key_chars.next(), key_chars.next()) {
+                if let Some(path) = path.as_str().and_then(expand_if_contains_tilde) {
+                    result.insert(key, path);
+                }
+            }
+        }
+    }
+
+    result
+}
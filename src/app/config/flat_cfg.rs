@@ -0,0 +1,25 @@
+use toml::Value;
+
+#[derive(Debug, Clone)]
+pub struct FlatConfig {
+    // Levels of subdirectories listed in flat view, 1 lists only directory itself
+    pub max_depth: usize,
+}
+
+impl Default for FlatConfig {
+    fn default() -> Self {
+        FlatConfig { max_depth: 3 }
+    }
+}
+
+impl FlatConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        if let Some(Value::Table(flat)) = cfg.get("flat") {
+            if let Some(Value::Integer(max_depth)) = flat.get("max_depth") {
+                if *max_depth > 0 {
+                    self.max_depth = *max_depth as usize;
+                }
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::core::key_binding::KeyBinding;
 #[derive(Debug, Clone)]
 pub struct KeyboardConfig {
     pub quit: KeyBinding,
+    pub quit_without_changing_dir: KeyBinding,
     pub focus_left_panel: KeyBinding,
     pub focus_right_panel: KeyBinding,
     pub move_down: KeyBinding,
@@ -14,6 +15,8 @@ pub struct KeyboardConfig {
     pub prev_tab: KeyBinding,
     pub close: KeyBinding,
     pub open: KeyBinding,
+    pub toggle_expanded: KeyBinding,
+    pub toggle_flat: KeyBinding,
     pub open_as_tab: KeyBinding,
     pub navigate_up: KeyBinding,
     pub delete: KeyBinding,
@@ -27,6 +30,30 @@ pub struct KeyboardConfig {
     pub search_in_panel: KeyBinding,
     pub select_prev: KeyBinding,
     pub select_next: KeyBinding,
+    pub connect_remote: KeyBinding,
+    pub mark_bookmark: KeyBinding,
+    pub jump_to_bookmark: KeyBinding,
+    pub show_bookmarks: KeyBinding,
+    pub history_back: KeyBinding,
+    pub history_forward: KeyBinding,
+    pub show_history: KeyBinding,
+    pub jump_to_directory: KeyBinding,
+    pub go_to_path: KeyBinding,
+    pub fuzzy_find: KeyBinding,
+    pub find: KeyBinding,
+    pub grep: KeyBinding,
+    pub toggle_preview: KeyBinding,
+    pub preview_scroll_down: KeyBinding,
+    pub preview_scroll_up: KeyBinding,
+    pub view: KeyBinding,
+    pub diff: KeyBinding,
+    pub toggle_miller_columns: KeyBinding,
+    pub toggle_zoom: KeyBinding,
+    pub cycle_split_direction: KeyBinding,
+    pub grow_panel: KeyBinding,
+    pub shrink_panel: KeyBinding,
+    pub toggle_gitignore: KeyBinding,
+    pub switch_search_mode: KeyBinding,
 }
 
 impl KeyboardConfig {
@@ -46,6 +73,19 @@ impl KeyboardConfig {
                     }
                 }
 
+                if let Some(Value::Table(key_binding)) =
+                    keyboard_cfg.get("quit_without_changing_dir")
+                {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.quit_without_changing_dir = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
                 if let Some(focus_left_panel) = keyboard_cfg.get("focus_left_panel") {
                     if let Value::Table(key_binding) = focus_left_panel {
                         let key_code = map_key(key_binding["key"].as_str().unwrap());
@@ -149,6 +189,28 @@ impl KeyboardConfig {
                     }
                 }
 
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_expanded") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_expanded = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_flat") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_flat = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
                 if let Some(open_as_tab) = keyboard_cfg.get("open_as_tab") {
                     if let Value::Table(key_binding) = open_as_tab {
                         let key_code = map_key(key_binding["key"].as_str().unwrap());
@@ -317,6 +379,270 @@ impl KeyboardConfig {
                         self.select_next = KeyBinding::with_modifiers(key_code, modifier);
                     }
                 }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("connect_remote") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.connect_remote = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("mark_bookmark") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.mark_bookmark = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("jump_to_bookmark") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.jump_to_bookmark = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("show_bookmarks") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.show_bookmarks = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("history_back") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.history_back = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("history_forward") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.history_forward = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("show_history") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.show_history = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("jump_to_directory") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.jump_to_directory = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("go_to_path") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.go_to_path = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("fuzzy_find") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.fuzzy_find = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("find") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.find = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("grep") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.grep = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_preview") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_preview = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("preview_scroll_down") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.preview_scroll_down = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("preview_scroll_up") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.preview_scroll_up = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("view") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.view = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("diff") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.diff = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_miller_columns") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_miller_columns = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_zoom") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_zoom = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("cycle_split_direction") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.cycle_split_direction = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("grow_panel") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.grow_panel = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("shrink_panel") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.shrink_panel = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("toggle_gitignore") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.toggle_gitignore = KeyBinding::with_modifiers(key_code, modifier);
+                }
+
+                if let Some(Value::Table(key_binding)) = keyboard_cfg.get("switch_search_mode") {
+                    let key_code = map_key(key_binding["key"].as_str().unwrap());
+                    let modifier = if key_binding.contains_key("modifier") {
+                        map_modifier(key_binding["modifier"].as_str().unwrap())
+                    } else {
+                        KeyModifiers::empty()
+                    };
+
+                    self.switch_search_mode = KeyBinding::with_modifiers(key_code, modifier);
+                }
             }
         }
     }
@@ -326,6 +652,10 @@ impl Default for KeyboardConfig {
     fn default() -> Self {
         KeyboardConfig {
             quit: KeyBinding::with_modifiers(KeyCode::Char('q'), KeyModifiers::CONTROL),
+            quit_without_changing_dir: KeyBinding::with_modifiers(
+                KeyCode::Char('q'),
+                KeyModifiers::ALT,
+            ),
             focus_left_panel: KeyBinding::new(KeyCode::Char('h')),
             focus_right_panel: KeyBinding::new(KeyCode::Char('l')),
             move_down: KeyBinding::new(KeyCode::Char('j')),
@@ -334,6 +664,8 @@ impl Default for KeyboardConfig {
             prev_tab: KeyBinding::new(KeyCode::Char('p')),
             close: KeyBinding::new(KeyCode::Esc),
             open: KeyBinding::new(KeyCode::Char('o')),
+            toggle_expanded: KeyBinding::new(KeyCode::Char('t')),
+            toggle_flat: KeyBinding::with_modifiers(KeyCode::Char('t'), KeyModifiers::ALT),
             open_as_tab: KeyBinding::with_modifiers(KeyCode::Char('o'), KeyModifiers::CONTROL),
             navigate_up: KeyBinding::new(KeyCode::Backspace),
             delete: KeyBinding::with_modifiers(KeyCode::Char('d'), KeyModifiers::CONTROL),
@@ -347,6 +679,36 @@ impl Default for KeyboardConfig {
             search_in_panel: KeyBinding::with_modifiers(KeyCode::Char('s'), KeyModifiers::CONTROL),
             select_prev: KeyBinding::with_modifiers(KeyCode::Char('k'), KeyModifiers::CONTROL),
             select_next: KeyBinding::with_modifiers(KeyCode::Char('j'), KeyModifiers::CONTROL),
+            connect_remote: KeyBinding::with_modifiers(KeyCode::Char('t'), KeyModifiers::CONTROL),
+            mark_bookmark: KeyBinding::new(KeyCode::Char('m')),
+            jump_to_bookmark: KeyBinding::new(KeyCode::Char('\'')),
+            show_bookmarks: KeyBinding::with_modifiers(KeyCode::Char('b'), KeyModifiers::CONTROL),
+            history_back: KeyBinding::with_modifiers(KeyCode::Left, KeyModifiers::ALT),
+            history_forward: KeyBinding::with_modifiers(KeyCode::Right, KeyModifiers::ALT),
+            show_history: KeyBinding::with_modifiers(KeyCode::Char('h'), KeyModifiers::ALT),
+            jump_to_directory: KeyBinding::with_modifiers(KeyCode::Char('z'), KeyModifiers::ALT),
+            go_to_path: KeyBinding::with_modifiers(KeyCode::Char('g'), KeyModifiers::CONTROL),
+            fuzzy_find: KeyBinding::with_modifiers(KeyCode::Char('f'), KeyModifiers::CONTROL),
+            find: KeyBinding::with_modifiers(KeyCode::Char('f'), KeyModifiers::ALT),
+            grep: KeyBinding::with_modifiers(KeyCode::Char('g'), KeyModifiers::ALT),
+            toggle_preview: KeyBinding::with_modifiers(KeyCode::Char('p'), KeyModifiers::ALT),
+            preview_scroll_down: KeyBinding::with_modifiers(KeyCode::Char('j'), KeyModifiers::ALT),
+            preview_scroll_up: KeyBinding::with_modifiers(KeyCode::Char('k'), KeyModifiers::ALT),
+            view: KeyBinding::new(KeyCode::F(3)),
+            diff: KeyBinding::with_modifiers(KeyCode::Char('d'), KeyModifiers::ALT),
+            toggle_miller_columns: KeyBinding::with_modifiers(
+                KeyCode::Char('m'),
+                KeyModifiers::ALT,
+            ),
+            toggle_zoom: KeyBinding::with_modifiers(KeyCode::Char('o'), KeyModifiers::ALT),
+            cycle_split_direction: KeyBinding::with_modifiers(
+                KeyCode::Char('v'),
+                KeyModifiers::ALT,
+            ),
+            grow_panel: KeyBinding::with_modifiers(KeyCode::Char('.'), KeyModifiers::ALT),
+            shrink_panel: KeyBinding::with_modifiers(KeyCode::Char(','), KeyModifiers::ALT),
+            toggle_gitignore: KeyBinding::with_modifiers(KeyCode::Char('i'), KeyModifiers::ALT),
+            switch_search_mode: KeyBinding::new(KeyCode::Tab),
         }
     }
 }
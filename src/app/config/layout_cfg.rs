@@ -0,0 +1,59 @@
+use toml::Value;
+
+// Neither panel can be shrunk until it disappears, zoom is for that
+pub const MIN_RATIO: u16 = 10;
+pub const MAX_RATIO: u16 = 90;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SplitDirection {
+    // Panels next to each other
+    #[default]
+    Horizontal,
+    // Panels above each other
+    Vertical,
+    // Vertical when terminal is narrower than configured width, horizontal otherwise
+    Auto,
+}
+
+#[derive(Debug, Clone)]
+pub struct LayoutConfig {
+    pub split: SplitDirection,
+    // Percentage of space taken by left (or top) panel
+    pub ratio: u16,
+    pub narrow_width: u16,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        LayoutConfig {
+            split: SplitDirection::Horizontal,
+            ratio: 50,
+            narrow_width: 100,
+        }
+    }
+}
+
+impl LayoutConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        if let Some(Value::Table(layout)) = cfg.get("layout") {
+            if let Some(Value::String(split)) = layout.get("split") {
+                match split.as_str() {
+                    "horizontal" => self.split = SplitDirection::Horizontal,
+                    "vertical" => self.split = SplitDirection::Vertical,
+                    "auto" => self.split = SplitDirection::Auto,
+                    _ => {}
+                }
+            }
+
+            if let Some(Value::Integer(ratio)) = layout.get("ratio") {
+                self.ratio = (*ratio).clamp(MIN_RATIO as i64, MAX_RATIO as i64) as u16;
+            }
+
+            if let Some(Value::Integer(narrow_width)) = layout.get("narrow_width") {
+                if *narrow_width > 0 {
+                    self.narrow_width = (*narrow_width).min(u16::MAX as i64) as u16;
+                }
+            }
+        }
+    }
+}
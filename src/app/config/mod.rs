@@ -1,18 +1,29 @@
 use toml::Value;
 
 use crate::core::config::CoreConfig;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use self::{
-    icon_cfg::IconsConfig, keyboard_cfg::KeyboardConfig,
-    program_associations::FileAssociatedPrograms,
+    bookmarks_cfg::BookmarksConfig, flat_cfg::FlatConfig, icon_cfg::IconsConfig,
+    keyboard_cfg::KeyboardConfig, layout_cfg::LayoutConfig, preview_cfg::PreviewConfig,
+    program_associations::FileAssociatedPrograms, read_only_cfg::ReadOnlyConfig,
+    search_cfg::SearchConfig,
 };
 
-use super::file_system::{functions::expand_if_contains_tilde, FileSystem};
+use super::{
+    bookmarks::BOOKMARKS_FILE_NAME,
+    file_system::{functions::expand_if_contains_tilde, FileSystem},
+};
 
+pub mod bookmarks_cfg;
+pub mod flat_cfg;
 pub mod icon_cfg;
 pub mod keyboard_cfg;
+pub mod layout_cfg;
+pub mod preview_cfg;
 pub mod program_associations;
+pub mod read_only_cfg;
+pub mod search_cfg;
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -20,6 +31,13 @@ pub struct Config {
     pub keyboard_cfg: KeyboardConfig,
     pub icons: IconsConfig,
     pub file_associated_programs: FileAssociatedPrograms,
+    pub read_only: ReadOnlyConfig,
+    pub bookmarks: BookmarksConfig,
+    pub search: SearchConfig,
+    pub preview: PreviewConfig,
+    pub layout: LayoutConfig,
+    pub flat: FlatConfig,
+    pub config_path: Option<PathBuf>,
 }
 
 impl Default for Config {
@@ -29,6 +47,13 @@ impl Default for Config {
             keyboard_cfg: KeyboardConfig::default(),
             icons: IconsConfig::default(),
             file_associated_programs: FileAssociatedPrograms::default(),
+            read_only: ReadOnlyConfig::default(),
+            bookmarks: BookmarksConfig::default(),
+            search: SearchConfig::default(),
+            preview: PreviewConfig::default(),
+            layout: LayoutConfig::default(),
+            flat: FlatConfig::default(),
+            config_path: None,
         }
     }
 }
@@ -39,28 +64,45 @@ impl Config {
         file_system: &TFileSystem,
     ) -> Self {
         let mut cfg = Config::default();
-        if let Some(config_content) = read_config_file_to_string(paths, file_system) {
+        if let Some((config_path, config_content)) = read_config_file_to_string(paths, file_system)
+        {
+            cfg.config_path = Some(config_path);
             if let Ok(toml_mapped_values) = config_content.parse::<Value>() {
                 cfg.icons.update_from_file(&toml_mapped_values);
                 cfg.keyboard_cfg.update_from_file(&toml_mapped_values);
                 cfg.file_associated_programs
                     .update_from_file(&toml_mapped_values);
                 cfg.core_cfg.update_from_file(&toml_mapped_values);
+                cfg.read_only.update_from_file(&toml_mapped_values);
+                cfg.bookmarks.update_from_file(&toml_mapped_values);
+                cfg.search.update_from_file(&toml_mapped_values);
+                cfg.preview.update_from_file(&toml_mapped_values);
+                cfg.layout.update_from_file(&toml_mapped_values);
+                cfg.flat.update_from_file(&toml_mapped_values);
             }
         }
         cfg
     }
+
+    pub fn bookmarks_file_path(&self) -> Option<PathBuf> {
+        match self.config_path.as_ref().and_then(|path| path.parent()) {
+            Some(config_dir) => Some(config_dir.join(BOOKMARKS_FILE_NAME)),
+            None => {
+                expand_if_contains_tilde("~/.config").map(|path| path.join(BOOKMARKS_FILE_NAME))
+            }
+        }
+    }
 }
 
 fn read_config_file_to_string<TPath: AsRef<Path>, TFileSystem: FileSystem>(
     paths: Vec<TPath>,
     file_system: &TFileSystem,
-) -> Option<String> {
+) -> Option<(PathBuf, String)> {
     for path in paths {
         if let Some(path) = expand_if_contains_tilde(path) {
             match file_system.read_to_string(&path) {
-                Some(content) => return Some(content.clone()),
-                None => continue,
+                Ok(content) => return Some((path, content.clone())),
+                Err(_) => continue,
             }
         }
     }
@@ -0,0 +1,51 @@
+use toml::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PreviewPosition {
+    // Preview takes place of the panel which is not focused
+    #[default]
+    InactivePanel,
+    // Focused panel is split into list and preview
+    Split,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviewConfig {
+    pub max_size_kb: u64,
+    pub position: PreviewPosition,
+    pub syntax_highlighting: bool,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        PreviewConfig {
+            max_size_kb: 64,
+            position: PreviewPosition::InactivePanel,
+            syntax_highlighting: true,
+        }
+    }
+}
+
+impl PreviewConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        if let Some(Value::Table(preview)) = cfg.get("preview") {
+            if let Some(Value::Integer(max_size_kb)) = preview.get("max_size_kb") {
+                if *max_size_kb > 0 {
+                    self.max_size_kb = *max_size_kb as u64;
+                }
+            }
+
+            if let Some(Value::String(position)) = preview.get("position") {
+                match position.as_str() {
+                    "inactive_panel" => self.position = PreviewPosition::InactivePanel,
+                    "split" => self.position = PreviewPosition::Split,
+                    _ => {}
+                }
+            }
+
+            if let Some(Value::Boolean(syntax_highlighting)) = preview.get("syntax_highlighting") {
+                self.syntax_highlighting = *syntax_highlighting;
+            }
+        }
+    }
+}
@@ -27,6 +27,11 @@ impl FileAssociatedPrograms {
             }
         }
     }
+    // True when extension has own program instead of default one
+    pub fn has_program(&self, file_extension: &str) -> bool {
+        self.bindings.contains_key(file_extension)
+    }
+
     pub fn get_program_name(&self, file_extension: String) -> String {
         match self.bindings.get(&file_extension) {
             Some(name) => name.clone(),
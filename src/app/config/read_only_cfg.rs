@@ -0,0 +1,44 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::Value;
+
+use crate::app::file_system::functions::expand_if_contains_tilde;
+
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlyConfig {
+    pub enabled: bool,
+    paths: Vec<PathBuf>,
+}
+
+impl ReadOnlyConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        if let Some(Value::Table(read_only)) = cfg.get("read_only") {
+            if let Some(Value::Boolean(enabled)) = read_only.get("enabled") {
+                self.enabled = *enabled;
+            }
+
+            if let Some(Value::Array(paths)) = read_only.get("paths") {
+                for path in paths.iter() {
+                    if let Some(path) = path.as_str().and_then(expand_if_contains_tilde) {
+                        self.paths
+                            .push(fs::canonicalize(path.as_path()).unwrap_or(path));
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_read_only<TPath: AsRef<Path>>(&self, path: TPath) -> bool {
+        if self.enabled {
+            return true;
+        }
+
+        let path = path.as_ref();
+        self.paths
+            .iter()
+            .any(|read_only_path| path.starts_with(read_only_path))
+    }
+}
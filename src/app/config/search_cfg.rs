@@ -0,0 +1,30 @@
+use toml::Value;
+
+#[derive(Debug, Clone)]
+pub struct SearchConfig {
+    pub respect_gitignore: bool,
+    pub show_hidden: bool,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        SearchConfig {
+            respect_gitignore: true,
+            show_hidden: false,
+        }
+    }
+}
+
+impl SearchConfig {
+    pub fn update_from_file(&mut self, cfg: &Value) {
+        if let Some(Value::Table(search)) = cfg.get("search") {
+            if let Some(Value::Boolean(respect_gitignore)) = search.get("respect_gitignore") {
+                self.respect_gitignore = *respect_gitignore;
+            }
+
+            if let Some(Value::Boolean(show_hidden)) = search.get("show_hidden") {
+                self.show_hidden = *show_hidden;
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use similar::{ChangeTag, TextDiff};
+
+use super::{hex::is_binary, preview::sanitize_line, FileSystem};
+
+// Diff is computed in memory, bigger files would make interface unresponsive
+pub const MAX_DIFF_SIZE: u64 = 8 * 1024 * 1024;
+// Unchanged lines shown around every change
+const CONTEXT_LINES: usize = 3;
+// Very different files give up on minimal diff instead of freezing
+const DIFF_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiffTag {
+    Equal,
+    Delete,
+    Insert,
+}
+
+#[derive(Clone, Debug)]
+pub struct DiffLine {
+    pub tag: DiffTag,
+    // Line numbers are counted from 1, missing on side where line does not exist
+    pub old_number: Option<usize>,
+    pub new_number: Option<usize>,
+    pub text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum DiffRow {
+    Header(String),
+    Unified(DiffLine),
+    // Deleted and inserted lines of the same change are paired next to each other
+    SideBySide(Option<DiffLine>, Option<DiffLine>),
+}
+
+// Rows of one display mode with indices of rows where hunks start
+#[derive(Clone, Debug, Default)]
+pub struct DiffView {
+    pub rows: Vec<DiffRow>,
+    pub hunk_starts: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FileDiff {
+    pub unified: DiffView,
+    pub side_by_side: DiffView,
+    pub old_lines_count: usize,
+    pub new_lines_count: usize,
+}
+
+impl FileDiff {
+    pub fn is_identical(&self) -> bool {
+        self.unified.hunk_starts.is_empty()
+    }
+}
+
+// Reads and compares files on background thread, remote files and big files
+// do not block the interface
+pub struct DiffLoader {
+    receiver: Receiver<Result<FileDiff, String>>,
+}
+
+impl DiffLoader {
+    pub fn start<TFileSystem: FileSystem + Clone>(
+        file_system: &TFileSystem,
+        old_path: PathBuf,
+        new_path: PathBuf,
+    ) -> Self {
+        let (sender, receiver) = channel();
+        let file_system = file_system.clone();
+        thread::spawn(move || {
+            let diff = diff_files(&file_system, &old_path, &new_path);
+            let _ = sender.send(diff.map_err(|err| err.to_string()));
+        });
+
+        DiffLoader { receiver }
+    }
+
+    pub fn wait(&self, timeout: Duration) -> Option<Result<FileDiff, String>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(diff) => Some(diff),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => Some(Err("Failed to compare files".to_string())),
+        }
+    }
+}
+
+// Read through file system so files on remote hosts can be compared too
+fn read_text<TFileSystem: FileSystem>(
+    file_system: &TFileSystem,
+    path: &Path,
+) -> io::Result<String> {
+    // Size is checked before reading, remote file would be downloaded whole otherwise
+    let with_path =
+        |err: io::Error| io::Error::new(err.kind(), format!("{}: {}", path.display(), err));
+    if file_system.file_size(path).map_err(with_path)? > MAX_DIFF_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} is too big to compare (over {} MB)",
+                path.display(),
+                MAX_DIFF_SIZE / 1024 / 1024
+            ),
+        ));
+    }
+
+    let content = file_system.read_to_string(path).map_err(with_path)?;
+    if is_binary(content.as_bytes()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} is binary file", path.display()),
+        ));
+    }
+
+    Ok(content)
+}
+
+pub fn diff_files<TFileSystem: FileSystem>(
+    file_system: &TFileSystem,
+    old_path: &Path,
+    new_path: &Path,
+) -> io::Result<FileDiff> {
+    let old_text = read_text(file_system, old_path)?;
+    let new_text = read_text(file_system, new_path)?;
+    let text_diff = TextDiff::configure()
+        .timeout(DIFF_TIMEOUT)
+        .diff_lines(&old_text, &new_text);
+
+    let mut file_diff = FileDiff {
+        old_lines_count: old_text.lines().count(),
+        new_lines_count: new_text.lines().count(),
+        ..FileDiff::default()
+    };
+    for group in text_diff.grouped_ops(CONTEXT_LINES) {
+        let (first, last) = match (group.first(), group.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => continue,
+        };
+        let old_range = first.old_range().start..last.old_range().end;
+        let new_range = first.new_range().start..last.new_range().end;
+        let header = format!(
+            "@@ -{},{} +{},{} @@",
+            old_range.start + 1,
+            old_range.len(),
+            new_range.start + 1,
+            new_range.len()
+        );
+
+        let mut lines = Vec::new();
+        for op in group.iter() {
+            for change in text_diff.iter_changes(op) {
+                lines.push(DiffLine {
+                    tag: match change.tag() {
+                        ChangeTag::Equal => DiffTag::Equal,
+                        ChangeTag::Delete => DiffTag::Delete,
+                        ChangeTag::Insert => DiffTag::Insert,
+                    },
+                    old_number: change.old_index().map(|idx| idx + 1),
+                    new_number: change.new_index().map(|idx| idx + 1),
+                    text: sanitize_line(change.value().trim_end_matches(&['\r', '\n'][..])),
+                });
+            }
+        }
+
+        push_unified(&mut file_diff.unified, header.clone(), &lines);
+        push_side_by_side(&mut file_diff.side_by_side, header, lines);
+    }
+
+    Ok(file_diff)
+}
+
+fn push_unified(view: &mut DiffView, header: String, lines: &[DiffLine]) {
+    view.hunk_starts.push(view.rows.len());
+    view.rows.push(DiffRow::Header(header));
+    view.rows
+        .extend(lines.iter().cloned().map(DiffRow::Unified));
+}
+
+fn push_side_by_side(view: &mut DiffView, header: String, lines: Vec<DiffLine>) {
+    view.hunk_starts.push(view.rows.len());
+    view.rows.push(DiffRow::Header(header));
+
+    // Deleted lines wait for inserted lines which replace them, until unchanged line comes
+    let mut unpaired = Vec::new();
+    for line in lines {
+        match line.tag {
+            DiffTag::Equal => {
+                unpaired.clear();
+                view.rows
+                    .push(DiffRow::SideBySide(Some(line.clone()), Some(line)));
+            }
+            DiffTag::Delete => {
+                unpaired.push(view.rows.len());
+                view.rows.push(DiffRow::SideBySide(Some(line), None));
+            }
+            DiffTag::Insert => {
+                if unpaired.is_empty() {
+                    view.rows.push(DiffRow::SideBySide(None, Some(line)));
+                } else {
+                    let idx = unpaired.remove(0);
+                    if let DiffRow::SideBySide(_, ref mut right) = view.rows[idx] {
+                        *right = Some(line);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -57,6 +57,26 @@ pub fn expand_if_contains_tilde<TPath: AsRef<Path>>(input: TPath) -> Option<Path
     })
 }
 
+// Human readable size with binary units eg. 512B, 1.5K, 23M
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if size < 10.0 {
+        format!("{:.1}{}", size, UNITS[unit])
+    } else {
+        format!("{:.0}{}", size, UNITS[unit])
+    }
+}
+
 pub fn map_dir_entry_to_file_system_item(
     dir_entry: DirEntry,
     icons: &IconsConfig,
@@ -0,0 +1,146 @@
+use std::{
+    fs::File,
+    io::Read,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{channel, Receiver, TryRecvError},
+        Arc,
+    },
+    thread,
+};
+
+use regex::Regex;
+
+use super::{hex::is_binary, walker::build_walk};
+
+// Files bigger than that are most likely not source code or text worth searching
+const MAX_FILE_SIZE: u64 = 16 * 1024 * 1024;
+const MAX_PREVIEW_LENGTH: usize = 200;
+
+#[derive(Clone, Debug)]
+pub struct GrepMatch {
+    pub path: PathBuf,
+    // Path relative to searched directory
+    pub relative_path: String,
+    // Numbered from 1 as editors do
+    pub line_number: usize,
+    pub line: String,
+}
+
+// Searches content of files in local directory tree on background thread,
+// search is stopped when it is dropped
+pub struct ContentSearch {
+    receiver: Receiver<Vec<GrepMatch>>,
+    cancelled: Arc<AtomicBool>,
+    finished: bool,
+}
+
+impl ContentSearch {
+    pub fn start(
+        root: PathBuf,
+        pattern: Regex,
+        respect_gitignore: bool,
+        show_hidden: bool,
+    ) -> Self {
+        let (sender, receiver) = channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let search_cancelled = cancelled.clone();
+
+        thread::spawn(move || {
+            for dir_entry in build_walk(&root, respect_gitignore, show_hidden) {
+                if search_cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let dir_entry = match dir_entry {
+                    Ok(dir_entry) => dir_entry,
+                    Err(_) => continue,
+                };
+                let is_file = dir_entry
+                    .file_type()
+                    .map(|file_type| file_type.is_file())
+                    .unwrap_or(false);
+                if !is_file {
+                    continue;
+                }
+
+                let path = dir_entry.path().to_path_buf();
+                let content = match read_text_file(&path) {
+                    Some(content) => content,
+                    None => continue,
+                };
+                let relative_path = path
+                    .strip_prefix(&root)
+                    .unwrap_or(path.as_path())
+                    .to_str()
+                    .unwrap_or("")
+                    .to_string();
+
+                let matches: Vec<GrepMatch> = content
+                    .lines()
+                    .enumerate()
+                    .filter(|(_, line)| pattern.is_match(line))
+                    .map(|(idx, line)| GrepMatch {
+                        path: path.clone(),
+                        relative_path: relative_path.clone(),
+                        line_number: idx + 1,
+                        line: line.trim().chars().take(MAX_PREVIEW_LENGTH).collect(),
+                    })
+                    .collect();
+                if !matches.is_empty() && sender.send(matches).is_err() {
+                    return;
+                }
+            }
+        });
+
+        ContentSearch {
+            receiver,
+            cancelled,
+            finished: false,
+        }
+    }
+
+    // Returns matches found since last call without blocking
+    pub fn poll(&mut self) -> Vec<GrepMatch> {
+        let mut result = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(mut matches) => result.append(&mut matches),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl Drop for ContentSearch {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+// Returns None for binary and too big files, invalid UTF-8 is replaced
+fn read_text_file(path: &PathBuf) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    if file.metadata().ok()?.len() > MAX_FILE_SIZE {
+        return None;
+    }
+
+    let mut content = Vec::new();
+    file.read_to_end(&mut content).ok()?;
+    if is_binary(&content) {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&content).into_owned())
+}
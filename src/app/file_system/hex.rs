@@ -0,0 +1,97 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+pub const BYTES_PER_LINE: usize = 16;
+// Same heuristic as git uses, NUL byte in first 8000 bytes means binary file
+const BINARY_CHECK_LENGTH: usize = 8000;
+
+pub fn is_binary(content: &[u8]) -> bool {
+    content[..content.len().min(BINARY_CHECK_LENGTH)].contains(&0)
+}
+
+pub fn is_binary_file(path: &Path) -> bool {
+    let mut content = Vec::new();
+    match File::open(path) {
+        Ok(file) => match file
+            .take(BINARY_CHECK_LENGTH as u64)
+            .read_to_end(&mut content)
+        {
+            Ok(_) => is_binary(&content),
+            Err(_) => false,
+        },
+        Err(_) => false,
+    }
+}
+
+// Reads at most length bytes starting at offset, less at the end of file
+pub fn read_chunk(path: &Path, offset: u64, length: usize) -> io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut content = Vec::with_capacity(length);
+    file.take(length as u64).read_to_end(&mut content)?;
+
+    Ok(content)
+}
+
+// Offset, bytes in hex and printable ASCII, eg.
+// 00000010  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 00  |Hello, world!...|
+pub fn hex_line(offset: u64, bytes: &[u8]) -> (String, String, String) {
+    let mut hex = String::with_capacity(BYTES_PER_LINE * 3 + 1);
+    for idx in 0..BYTES_PER_LINE {
+        if idx == BYTES_PER_LINE / 2 {
+            hex.push(' ');
+        }
+        match bytes.get(idx) {
+            Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+            None => hex.push_str("   "),
+        }
+    }
+    let ascii: String = bytes
+        .iter()
+        .map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+
+    (format!("{:08x}  ", offset), hex, format!(" |{}|", ascii))
+}
+
+// Offset is decimal or hexadecimal with 0x prefix
+pub fn parse_offset(input: &str) -> Option<u64> {
+    let input = input.trim();
+    match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => input.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_offset_accepts_decimal_and_hex() {
+        assert_eq!(parse_offset("1024"), Some(1024));
+        assert_eq!(parse_offset(" 0x400 "), Some(1024));
+        assert_eq!(parse_offset("0XfF"), Some(255));
+    }
+
+    #[test]
+    fn parse_offset_rejects_invalid_input() {
+        assert_eq!(parse_offset(""), None);
+        assert_eq!(parse_offset("0x"), None);
+        assert_eq!(parse_offset("-1"), None);
+        assert_eq!(parse_offset("ff"), None);
+        assert_eq!(parse_offset("0x1ffffffffffffffff"), None);
+    }
+}
@@ -7,22 +7,35 @@ use std::{io, path::PathBuf};
 use self::{
     file_system_item::FileSystemItem,
     functions::{create_link, map_dir_entry_to_file_system_item},
+    sftp_file_system::{RemotePath, SftpFileSystem},
 };
 
 use super::config::icon_cfg::IconsConfig;
 
+pub mod diff;
 pub mod dir_item;
 pub mod file_item;
 pub mod file_system_item;
 pub mod functions;
+pub mod grep;
+pub mod hex;
+pub mod preview;
+pub mod read_only_file_system;
+pub mod sftp_file_system;
+pub mod ssh_config;
 pub mod symlink_item;
+pub mod viewer;
+pub mod walker;
 
-pub trait FileSystem {
+// File system is moved to background threads which read files (eg. diff)
+pub trait FileSystem: Send + 'static {
     fn exist<TPath: AsRef<Path>>(&self, path: TPath) -> bool;
     fn get_dir_info<TPath: AsRef<Path>>(&self, path: TPath) -> Option<DirInfo>;
     fn list_dir<TPath: AsRef<Path>>(&self, path: TPath, icons: &IconsConfig)
         -> Vec<FileSystemItem>;
-    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> Option<String>;
+    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<String>;
+    fn file_size<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<u64>;
+    fn read_link<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<PathBuf>;
     fn delete_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()>;
     fn delete_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()>;
     fn delete_empty_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()>;
@@ -32,7 +45,7 @@ pub trait FileSystem {
         source: TPath,
         target: TPath,
     ) -> io::Result<()>;
-    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<File>;
+    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()>;
     fn create_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()>;
     fn copy_file<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64>;
     fn copy_dir<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64>;
@@ -68,11 +81,16 @@ impl FileSystem for PhysicalFileSystem {
         }
     }
 
-    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> Option<String> {
-        match fs::read_to_string(path) {
-            Ok(content) => return Some(content.clone()),
-            Err(_) => None,
-        }
+    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn file_size<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<u64> {
+        fs::metadata(path).map(|metadata| metadata.len())
+    }
+
+    fn read_link<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<PathBuf> {
+        fs::read_link(path)
     }
 
     fn delete_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
@@ -95,8 +113,8 @@ impl FileSystem for PhysicalFileSystem {
         create_link(target, source)
     }
 
-    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<File> {
-        File::create(path)
+    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        File::create(path).map(|_| ())
     }
 
     fn create_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
@@ -134,6 +152,161 @@ impl FileSystem for PhysicalFileSystem {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct CompositeFileSystem {
+    local: PhysicalFileSystem,
+    remote: SftpFileSystem,
+}
+
+impl FileSystem for CompositeFileSystem {
+    fn get_dir_info<TPath: AsRef<Path>>(&self, path: TPath) -> Option<DirInfo> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.get_dir_info(&remote),
+            None => self.local.get_dir_info(path),
+        }
+    }
+
+    fn list_dir<TPath: AsRef<Path>>(
+        &self,
+        path: TPath,
+        icons: &IconsConfig,
+    ) -> Vec<FileSystemItem> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.list_dir(&remote, icons),
+            None => self.local.list_dir(path, icons),
+        }
+    }
+
+    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<String> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.read_to_string(&remote),
+            None => self.local.read_to_string(path),
+        }
+    }
+
+    fn file_size<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<u64> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.file_size(&remote),
+            None => self.local.file_size(path),
+        }
+    }
+
+    fn read_link<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<PathBuf> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self
+                .remote
+                .read_link(&remote)
+                .map(|target| target.to_path_buf()),
+            None => self.local.read_link(path),
+        }
+    }
+
+    fn delete_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.delete_file(&remote),
+            None => self.local.delete_file(path),
+        }
+    }
+
+    fn delete_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.delete_dir(&remote),
+            None => self.local.delete_dir(path),
+        }
+    }
+
+    fn delete_empty_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.delete_empty_dir(&remote),
+            None => self.local.delete_empty_dir(path),
+        }
+    }
+
+    fn rename_item<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<()> {
+        match (RemotePath::parse(&source), RemotePath::parse(&target)) {
+            (None, None) => self.local.rename_item(source, target),
+            (Some(remote_source), Some(remote_target))
+                if remote_source.host == remote_target.host =>
+            {
+                self.remote.rename_item(&remote_source, &remote_target)
+            }
+            _ => {
+                if self.get_dir_info(&source).is_some() {
+                    self.copy_dir(&source, &target)?;
+                    self.delete_dir(&source)
+                } else {
+                    self.copy_file(&source, &target)?;
+                    self.delete_file(&source)
+                }
+            }
+        }
+    }
+
+    fn create_symlink<TPath: AsRef<Path>>(
+        &mut self,
+        source: TPath,
+        target: TPath,
+    ) -> io::Result<()> {
+        match (RemotePath::parse(&source), RemotePath::parse(&target)) {
+            (None, None) => self.local.create_symlink(source, target),
+            (Some(remote_source), Some(remote_target)) => {
+                self.remote.create_symlink(&remote_source, &remote_target)
+            }
+            _ => Err(io::Error::other(
+                "Can't create symlink between local and remote file system",
+            )),
+        }
+    }
+
+    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.create_file(&remote),
+            None => self.local.create_file(path),
+        }
+    }
+
+    fn create_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        match RemotePath::parse(&path) {
+            Some(remote) => self.remote.create_dir(&remote),
+            None => self.local.create_dir(path),
+        }
+    }
+
+    fn copy_file<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64> {
+        match (RemotePath::parse(&source), RemotePath::parse(&target)) {
+            (None, None) => self.local.copy_file(source, target),
+            (None, Some(remote_target)) => self.remote.upload_file(source.as_ref(), &remote_target),
+            (Some(remote_source), None) => {
+                self.remote.download_file(&remote_source, target.as_ref())
+            }
+            (Some(remote_source), Some(remote_target)) => {
+                self.remote.copy_file(&remote_source, &remote_target)
+            }
+        }
+    }
+
+    fn copy_dir<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64> {
+        match (RemotePath::parse(&source), RemotePath::parse(&target)) {
+            (None, None) => self.local.copy_dir(source, target),
+            (None, Some(remote_target)) => self.remote.upload_dir(source.as_ref(), &remote_target),
+            (Some(remote_source), None) => {
+                self.remote.download_dir(&remote_source, target.as_ref())
+            }
+            (Some(remote_source), Some(remote_target)) => {
+                self.remote.copy_dir(&remote_source, &remote_target)
+            }
+        }
+    }
+
+    fn exist<TPath: AsRef<Path>>(&self, path: TPath) -> bool {
+        match RemotePath::parse(&path) {
+            //Remote items are not watched, checking every item on each tick would flood the connection
+            Some(_) => true,
+            None => self.local.exist(path),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DirInfo {
     pub name: String,
@@ -142,7 +315,8 @@ pub struct DirInfo {
 
 impl DirInfo {
     pub fn new<TPath: AsRef<Path>>(path: &TPath) -> Option<Self> {
-        if let Ok(path_buffer) = fs::canonicalize(path) {
+        // Canonicalized file path is valid too, but it can't be listed as directory
+        if let Some(path_buffer) = fs::canonicalize(path).ok().filter(|path| path.is_dir()) {
             let name = if let Some(file_name) = path_buffer.file_name() {
                 file_name.to_str().unwrap_or("")
             } else {
@@ -0,0 +1,172 @@
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::app::syntax::{highlight, LineHighlight};
+
+use super::hex::is_binary;
+
+const TAB_WIDTH: usize = 4;
+// Directory preview lists that many children, rest is only counted
+const MAX_DIR_ENTRIES: usize = 1000;
+
+#[derive(Clone, Debug)]
+pub enum PreviewContent {
+    Text {
+        lines: Vec<String>,
+        // Token kinds of every line when file type is known
+        highlights: Option<Vec<LineHighlight>>,
+        // File is bigger than loaded part
+        truncated: bool,
+    },
+    // Shown as hex dump
+    Binary {
+        bytes: Vec<u8>,
+        truncated: bool,
+    },
+    Directory {
+        // Sorted by name as in panel
+        entries: Vec<DirEntryPreview>,
+        dirs_count: usize,
+        files_count: usize,
+        // Sum of sizes of files directly in directory
+        total_size: u64,
+    },
+    Error(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct DirEntryPreview {
+    pub name: String,
+    pub is_dir: bool,
+    pub is_symlink: bool,
+    // Files only, size of directory is not known without walking it
+    pub size: Option<u64>,
+}
+
+// Reads beginning of local file on background thread so big files
+// and slow disks do not block the interface
+pub struct PreviewLoader {
+    receiver: Receiver<PreviewContent>,
+}
+
+impl PreviewLoader {
+    pub fn start(path: PathBuf, max_size: u64, syntax_highlighting: bool) -> Self {
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            let _ = sender.send(load_preview(&path, max_size, syntax_highlighting));
+        });
+
+        PreviewLoader { receiver }
+    }
+
+    // Waits at most timeout for content, loading is usually done by then
+    // which saves a tick of delay
+    pub fn wait(&self, timeout: Duration) -> Option<PreviewContent> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(content) => Some(content),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => {
+                Some(PreviewContent::Error("Failed to load preview".to_string()))
+            }
+        }
+    }
+}
+
+fn load_preview(path: &Path, max_size: u64, syntax_highlighting: bool) -> PreviewContent {
+    if path.is_dir() {
+        return load_dir_preview(path);
+    }
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return PreviewContent::Error(err.to_string()),
+    };
+    let file_size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+    let mut content = Vec::new();
+    if let Err(err) = file.take(max_size).read_to_end(&mut content) {
+        return PreviewContent::Error(err.to_string());
+    }
+
+    if is_binary(&content) {
+        return PreviewContent::Binary {
+            bytes: content,
+            truncated: file_size > max_size,
+        };
+    }
+
+    let lines: Vec<String> = String::from_utf8_lossy(&content)
+        .lines()
+        .map(sanitize_line)
+        .collect();
+
+    let highlights = if syntax_highlighting {
+        highlight(path, &lines)
+    } else {
+        None
+    };
+
+    PreviewContent::Text {
+        lines,
+        highlights,
+        truncated: file_size > max_size,
+    }
+}
+
+// Terminal cells can not display tabs and control characters
+pub fn sanitize_line(line: &str) -> String {
+    line.replace('\t', &" ".repeat(TAB_WIDTH))
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect()
+}
+
+fn load_dir_preview(path: &Path) -> PreviewContent {
+    let read_dir = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir,
+        Err(err) => return PreviewContent::Error(err.to_string()),
+    };
+
+    let mut entries: Vec<DirEntryPreview> = read_dir
+        .filter_map(|dir_entry| dir_entry.ok())
+        .map(|dir_entry| {
+            let is_symlink = dir_entry
+                .file_type()
+                .map(|file_type| file_type.is_symlink())
+                .unwrap_or(false);
+            // Symlinks are described by their targets
+            let metadata = fs::metadata(dir_entry.path()).ok();
+            let is_dir = metadata
+                .as_ref()
+                .map(|metadata| metadata.is_dir())
+                .unwrap_or(false);
+            DirEntryPreview {
+                name: dir_entry.file_name().to_string_lossy().into_owned(),
+                is_dir,
+                is_symlink,
+                size: match metadata {
+                    Some(metadata) if !is_dir => Some(metadata.len()),
+                    _ => None,
+                },
+            }
+        })
+        .collect();
+    entries.sort_by(|one, two| one.name.cmp(&two.name));
+
+    let dirs_count = entries.iter().filter(|entry| entry.is_dir).count();
+    let total_size = entries.iter().filter_map(|entry| entry.size).sum();
+    let files_count = entries.len() - dirs_count;
+    entries.truncate(MAX_DIR_ENTRIES);
+
+    PreviewContent::Directory {
+        entries,
+        dirs_count,
+        files_count,
+        total_size,
+    }
+}
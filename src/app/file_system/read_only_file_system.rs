@@ -0,0 +1,115 @@
+use std::{
+    fmt::Debug,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::app::config::{icon_cfg::IconsConfig, read_only_cfg::ReadOnlyConfig};
+
+use super::{file_system_item::FileSystemItem, DirInfo, FileSystem};
+
+#[derive(Clone, Debug, Default)]
+pub struct ReadOnlyFileSystem<TFileSystem: Clone + Debug + Default + FileSystem> {
+    inner: TFileSystem,
+    read_only: ReadOnlyConfig,
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> ReadOnlyFileSystem<TFileSystem> {
+    pub fn new(inner: TFileSystem, read_only: ReadOnlyConfig) -> Self {
+        ReadOnlyFileSystem { inner, read_only }
+    }
+
+    fn check_writable<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<()> {
+        if self.read_only.is_read_only(&path) {
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} is read-only", path.as_ref().to_str().unwrap_or("")),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<TFileSystem: Clone + Debug + Default + FileSystem> FileSystem
+    for ReadOnlyFileSystem<TFileSystem>
+{
+    fn exist<TPath: AsRef<Path>>(&self, path: TPath) -> bool {
+        self.inner.exist(path)
+    }
+
+    fn get_dir_info<TPath: AsRef<Path>>(&self, path: TPath) -> Option<DirInfo> {
+        self.inner.get_dir_info(path)
+    }
+
+    fn list_dir<TPath: AsRef<Path>>(
+        &self,
+        path: TPath,
+        icons: &IconsConfig,
+    ) -> Vec<FileSystemItem> {
+        self.inner.list_dir(path, icons)
+    }
+
+    fn read_to_string<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<String> {
+        self.inner.read_to_string(path)
+    }
+
+    fn file_size<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<u64> {
+        self.inner.file_size(path)
+    }
+
+    fn read_link<TPath: AsRef<Path>>(&self, path: TPath) -> io::Result<PathBuf> {
+        self.inner.read_link(path)
+    }
+
+    fn delete_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        self.check_writable(&path)?;
+        self.inner.delete_file(path)
+    }
+
+    fn delete_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        self.check_writable(&path)?;
+        self.inner.delete_dir(path)
+    }
+
+    fn delete_empty_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        self.check_writable(&path)?;
+        self.inner.delete_empty_dir(path)
+    }
+
+    fn rename_item<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<()> {
+        self.check_writable(&source)?;
+        self.check_writable(&target)?;
+        self.inner.rename_item(source, target)
+    }
+
+    fn create_symlink<TPath: AsRef<Path>>(
+        &mut self,
+        source: TPath,
+        target: TPath,
+    ) -> io::Result<()> {
+        // Only link itself is written, target is left untouched
+        self.check_writable(&source)?;
+        self.inner.create_symlink(source, target)
+    }
+
+    fn create_file<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        self.check_writable(&path)?;
+        self.inner.create_file(path)
+    }
+
+    fn create_dir<TPath: AsRef<Path>>(&mut self, path: TPath) -> io::Result<()> {
+        self.check_writable(&path)?;
+        self.inner.create_dir(path)
+    }
+
+    fn copy_file<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64> {
+        self.check_writable(&target)?;
+        self.inner.copy_file(source, target)
+    }
+
+    fn copy_dir<TPath: AsRef<Path>>(&mut self, source: TPath, target: TPath) -> io::Result<u64> {
+        self.check_writable(&target)?;
+        self.inner.copy_dir(source, target)
+    }
+}
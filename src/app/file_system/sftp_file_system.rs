@@ -0,0 +1,670 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs::{self, File},
+    io::{self, Read},
+    net::{TcpStream, ToSocketAddrs},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Local, TimeZone};
+use ssh2::{CheckResult, ErrorCode, FileStat, KnownHostFileKind, Session, Sftp};
+
+// Not re-exported by ssh2, value from libssh2.h
+const LIBSSH2_ERROR_TIMEOUT: i32 = -9;
+
+use crate::app::config::icon_cfg::IconsConfig;
+
+use super::{
+    dir_item::DirItem, file_item::FileItem, file_system_item::FileSystemItem,
+    functions::expand_if_contains_tilde, ssh_config::SshHostConfig, symlink_item::SymlinkItem,
+    DirInfo,
+};
+
+pub const SFTP_PREFIX: &str = "sftp://";
+// Dead host would otherwise block interface until system TCP timeout
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const SESSION_TIMEOUT_MS: u32 = 30_000;
+
+pub struct RemotePath {
+    pub host: String,
+    pub path: PathBuf,
+}
+
+impl RemotePath {
+    pub fn parse<TPath: AsRef<Path>>(path: TPath) -> Option<Self> {
+        let path = path.as_ref().to_str()?;
+        let rest = path.strip_prefix(SFTP_PREFIX)?;
+        let (host, remote_path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, "/"),
+        };
+        if host.is_empty() {
+            return None;
+        }
+
+        Some(RemotePath {
+            host: host.to_string(),
+            path: match remote_path.strip_prefix("/~") {
+                Some(home_relative) => PathBuf::from(format!("~{}", home_relative)),
+                None => PathBuf::from(remote_path),
+            },
+        })
+    }
+
+    pub fn to_path_buf(&self) -> PathBuf {
+        let path = self.path.to_str().unwrap_or("/");
+        PathBuf::from(format!(
+            "{}{}{}{}",
+            SFTP_PREFIX,
+            self.host,
+            if path.starts_with('/') { "" } else { "/" },
+            path
+        ))
+    }
+
+    // Remote root and home directory have no parent that can be known without connecting
+    pub fn parent(&self) -> Option<Self> {
+        let parent = self.path.parent()?;
+        if parent.as_os_str().is_empty() {
+            return None;
+        }
+
+        Some(RemotePath {
+            host: self.host.clone(),
+            path: parent.to_path_buf(),
+        })
+    }
+
+    fn join<TPath: AsRef<Path>>(&self, name: TPath) -> Self {
+        RemotePath {
+            host: self.host.clone(),
+            path: self.path.join(name),
+        }
+    }
+}
+
+struct SftpConnection {
+    _session: Session,
+    sftp: Sftp,
+}
+
+#[derive(Clone, Default)]
+pub struct SftpFileSystem {
+    connections: Arc<Mutex<HashMap<String, SftpConnection>>>,
+}
+
+impl Debug for SftpFileSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hosts: Vec<String> = match self.connections.lock() {
+            Ok(connections) => connections.keys().cloned().collect(),
+            Err(_) => Vec::new(),
+        };
+        f.debug_struct("SftpFileSystem")
+            .field("hosts", &hosts)
+            .finish()
+    }
+}
+
+impl SftpFileSystem {
+    fn with_sftp<TResult, TCallback>(&self, host: &str, callback: TCallback) -> io::Result<TResult>
+    where
+        TCallback: FnOnce(&Sftp) -> io::Result<TResult>,
+    {
+        let mut connections = self
+            .connections
+            .lock()
+            .map_err(|_| io::Error::other("SFTP connection lock poisoned"))?;
+        if !connections.contains_key(host) {
+            connections.insert(host.to_string(), connect(host)?);
+        }
+
+        // Broken connection is dropped so next operation connects again
+        let result = callback(&connections[host].sftp);
+        if let Err(ref err) = result {
+            if is_connection_error(err) {
+                connections.remove(host);
+            }
+        }
+
+        result
+    }
+
+    fn resolve(&self, remote: &RemotePath) -> io::Result<PathBuf> {
+        let path = remote.path.to_str().unwrap_or("/");
+        if path == "~" || path.starts_with("~/") {
+            let home = self.with_sftp(&remote.host, |sftp| {
+                sftp.realpath(Path::new(".")).map_err(sftp_error)
+            })?;
+            Ok(home.join(path.trim_start_matches('~').trim_start_matches('/')))
+        } else {
+            Ok(remote.path.clone())
+        }
+    }
+
+    pub fn get_dir_info(&self, remote: &RemotePath) -> Option<DirInfo> {
+        let path = self.resolve(remote).ok()?;
+        self.with_sftp(&remote.host, |sftp| {
+            let real_path = sftp.realpath(path.as_path()).map_err(sftp_error)?;
+            if sftp.stat(real_path.as_path()).map_err(sftp_error)?.is_dir() {
+                Ok(real_path)
+            } else {
+                Err(io::Error::other("Not a directory"))
+            }
+        })
+        .ok()
+        .map(|real_path| {
+            let name = match real_path.file_name() {
+                Some(name) => name.to_str().unwrap_or("").to_string(),
+                None => remote.host.clone(),
+            };
+            DirInfo {
+                name,
+                path: RemotePath {
+                    host: remote.host.clone(),
+                    path: real_path,
+                }
+                .to_path_buf(),
+            }
+        })
+    }
+
+    pub fn list_dir(&self, remote: &RemotePath, icons: &IconsConfig) -> Vec<FileSystemItem> {
+        let path = match self.resolve(remote) {
+            Ok(path) => path,
+            Err(_) => return Vec::new(),
+        };
+        let remote = RemotePath {
+            host: remote.host.clone(),
+            path,
+        };
+        let entries = self
+            .with_sftp(&remote.host, |sftp| {
+                let mut result = Vec::new();
+                for (entry_path, stat) in sftp.readdir(remote.path.as_path()).map_err(sftp_error)? {
+                    let target = if stat.file_type().is_symlink() {
+                        sftp.readlink(entry_path.as_path()).ok()
+                    } else {
+                        None
+                    };
+                    result.push((entry_path, stat, target));
+                }
+                Ok(result)
+            })
+            .unwrap_or_default();
+
+        let mut result: Vec<FileSystemItem> = entries
+            .into_iter()
+            .map(|(entry_path, stat, target)| {
+                map_remote_entry_to_file_system_item(&remote, entry_path, stat, target, icons)
+            })
+            .collect();
+        result.sort_by_key(|one| one.get_name());
+
+        result
+    }
+
+    pub fn exist(&self, remote: &RemotePath) -> bool {
+        match self.resolve(remote) {
+            Ok(path) => self
+                .with_sftp(&remote.host, |sftp| {
+                    sftp.lstat(path.as_path()).map_err(sftp_error)
+                })
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    pub fn read_to_string(&self, remote: &RemotePath) -> io::Result<String> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| {
+            let mut content = String::new();
+            sftp.open(path.as_path())
+                .map_err(sftp_error)?
+                .read_to_string(&mut content)?;
+            Ok(content)
+        })
+    }
+
+    pub fn file_size(&self, remote: &RemotePath) -> io::Result<u64> {
+        let path = self.resolve(remote)?;
+        let stat = self.with_sftp(&remote.host, |sftp| {
+            sftp.stat(path.as_path()).map_err(sftp_error)
+        })?;
+        Ok(stat.size.unwrap_or(0))
+    }
+
+    // Relative link target is relative to directory of link, not to local working directory
+    pub fn read_link(&self, remote: &RemotePath) -> io::Result<RemotePath> {
+        let path = self.resolve(remote)?;
+        let target = self.with_sftp(&remote.host, |sftp| {
+            sftp.readlink(path.as_path()).map_err(sftp_error)
+        })?;
+        Ok(RemotePath {
+            host: remote.host.clone(),
+            path: match path.parent() {
+                Some(parent) if target.is_relative() => parent.join(target),
+                _ => target,
+            },
+        })
+    }
+
+    pub fn delete_file(&self, remote: &RemotePath) -> io::Result<()> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| {
+            sftp.unlink(path.as_path()).map_err(sftp_error)
+        })
+    }
+
+    pub fn delete_empty_dir(&self, remote: &RemotePath) -> io::Result<()> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| {
+            sftp.rmdir(path.as_path()).map_err(sftp_error)
+        })
+    }
+
+    pub fn delete_dir(&self, remote: &RemotePath) -> io::Result<()> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| delete_remote_dir(sftp, path.as_path()))
+    }
+
+    pub fn rename_item(&self, source: &RemotePath, target: &RemotePath) -> io::Result<()> {
+        let source_path = self.resolve(source)?;
+        let target_path = self.resolve(target)?;
+        self.with_sftp(&source.host, |sftp| {
+            sftp.rename(source_path.as_path(), target_path.as_path(), None)
+                .map_err(sftp_error)
+        })
+    }
+
+    pub fn create_symlink(&self, source: &RemotePath, target: &RemotePath) -> io::Result<()> {
+        let source_path = self.resolve(source)?;
+        let target_path = self.resolve(target)?;
+        self.with_sftp(&source.host, |sftp| {
+            sftp.symlink(source_path.as_path(), target_path.as_path())
+                .map_err(sftp_error)
+        })
+    }
+
+    pub fn create_file(&self, remote: &RemotePath) -> io::Result<()> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| {
+            sftp.create(path.as_path()).map_err(sftp_error)?;
+            Ok(())
+        })
+    }
+
+    pub fn create_dir(&self, remote: &RemotePath) -> io::Result<()> {
+        let path = self.resolve(remote)?;
+        self.with_sftp(&remote.host, |sftp| {
+            sftp.mkdir(path.as_path(), 0o755).map_err(sftp_error)
+        })
+    }
+
+    pub fn copy_file(&self, source: &RemotePath, target: &RemotePath) -> io::Result<u64> {
+        let source_path = self.resolve(source)?;
+        if source.host == target.host {
+            let target_path = self.resolve(target)?;
+            self.with_sftp(&source.host, |sftp| {
+                let mut source_file = sftp.open(source_path.as_path()).map_err(sftp_error)?;
+                let mut target_file = sftp.create(target_path.as_path()).map_err(sftp_error)?;
+                io::copy(&mut source_file, &mut target_file)
+            })
+        } else {
+            let mut content = Vec::new();
+            self.with_sftp(&source.host, |sftp| {
+                sftp.open(source_path.as_path())
+                    .map_err(sftp_error)?
+                    .read_to_end(&mut content)
+            })?;
+            self.upload_bytes(content.as_slice(), target)
+        }
+    }
+
+    pub fn copy_dir(&self, source: &RemotePath, target: &RemotePath) -> io::Result<u64> {
+        let source_path = self.resolve(source)?;
+        let entries = self.with_sftp(&source.host, |sftp| {
+            sftp.readdir(source_path.as_path()).map_err(sftp_error)
+        })?;
+        if !self.exist(target) {
+            self.create_dir(target)?;
+        }
+        for (entry_path, stat) in entries {
+            let name = match entry_path.file_name() {
+                Some(name) => name.to_owned(),
+                None => continue,
+            };
+            let source_entry = RemotePath {
+                host: source.host.clone(),
+                path: entry_path.clone(),
+            };
+            if stat.is_dir() {
+                self.copy_dir(&source_entry, &target.join(name))?;
+            } else {
+                self.copy_file(&source_entry, &target.join(name))?;
+            }
+        }
+        Ok(0)
+    }
+
+    pub fn upload_file(&self, source: &Path, target: &RemotePath) -> io::Result<u64> {
+        let mut source_file = File::open(source)?;
+        let target_path = self.resolve(target)?;
+        self.with_sftp(&target.host, |sftp| {
+            let mut target_file = sftp.create(target_path.as_path()).map_err(sftp_error)?;
+            io::copy(&mut source_file, &mut target_file)
+        })
+    }
+
+    pub fn upload_dir(&self, source: &Path, target: &RemotePath) -> io::Result<u64> {
+        if !self.exist(target) {
+            self.create_dir(target)?;
+        }
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                self.upload_dir(entry.path().as_path(), &target.join(entry.file_name()))?;
+            } else {
+                self.upload_file(entry.path().as_path(), &target.join(entry.file_name()))?;
+            }
+        }
+        Ok(0)
+    }
+
+    pub fn download_file(&self, source: &RemotePath, target: &Path) -> io::Result<u64> {
+        let source_path = self.resolve(source)?;
+        let mut target_file = File::create(target)?;
+        self.with_sftp(&source.host, |sftp| {
+            let mut source_file = sftp.open(source_path.as_path()).map_err(sftp_error)?;
+            io::copy(&mut source_file, &mut target_file)
+        })
+    }
+
+    pub fn download_dir(&self, source: &RemotePath, target: &Path) -> io::Result<u64> {
+        let source_path = self.resolve(source)?;
+        let entries = self.with_sftp(&source.host, |sftp| {
+            sftp.readdir(source_path.as_path()).map_err(sftp_error)
+        })?;
+        fs::create_dir_all(target)?;
+        for (entry_path, stat) in entries {
+            let name = match entry_path.file_name() {
+                Some(name) => name.to_owned(),
+                None => continue,
+            };
+            let source_entry = RemotePath {
+                host: source.host.clone(),
+                path: entry_path.clone(),
+            };
+            if stat.is_dir() {
+                self.download_dir(&source_entry, target.join(name).as_path())?;
+            } else {
+                self.download_file(&source_entry, target.join(name).as_path())?;
+            }
+        }
+        Ok(0)
+    }
+
+    fn upload_bytes(&self, content: &[u8], target: &RemotePath) -> io::Result<u64> {
+        let target_path = self.resolve(target)?;
+        self.with_sftp(&target.host, |sftp| {
+            let mut source = content;
+            let mut target_file = sftp.create(target_path.as_path()).map_err(sftp_error)?;
+            io::copy(&mut source, &mut target_file)
+        })
+    }
+}
+
+fn connect(host: &str) -> io::Result<SftpConnection> {
+    let ssh_config =
+        expand_if_contains_tilde("~/.ssh/config").and_then(|path| fs::read_to_string(path).ok());
+    let host_config = SshHostConfig::resolve(host, ssh_config);
+
+    let tcp = connect_tcp(host_config.host_name.as_str(), host_config.port)?;
+    let mut session = Session::new()?;
+    session.set_tcp_stream(tcp);
+    session.set_timeout(SESSION_TIMEOUT_MS);
+    session.handshake()?;
+    verify_host_key(&session, &host_config)?;
+
+    if session.userauth_agent(host_config.user.as_str()).is_err() {
+        for identity_file in host_config.identity_files.iter() {
+            if identity_file.exists()
+                && session
+                    .userauth_pubkey_file(
+                        host_config.user.as_str(),
+                        None,
+                        identity_file.as_path(),
+                        None,
+                    )
+                    .is_ok()
+            {
+                break;
+            }
+        }
+    }
+
+    if !session.authenticated() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "Can't authenticate {}@{}",
+                host_config.user, host_config.host_name
+            ),
+        ));
+    }
+
+    let sftp = session.sftp()?;
+    Ok(SftpConnection {
+        _session: session,
+        sftp,
+    })
+}
+
+fn connect_tcp(host_name: &str, port: u16) -> io::Result<TcpStream> {
+    let mut last_error = io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("Can't resolve {}", host_name),
+    );
+    for address in (host_name, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) {
+            Ok(tcp) => return Ok(tcp),
+            Err(err) => last_error = err,
+        }
+    }
+
+    Err(last_error)
+}
+
+// Only hosts already accepted by ssh are trusted, user's agent is not offered to unknown host
+fn verify_host_key(session: &Session, host_config: &SshHostConfig) -> io::Result<()> {
+    let (key, _) = session.host_key().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} didn't send host key", host_config.host_name),
+        )
+    })?;
+    let mut known_hosts = session.known_hosts()?;
+    if let Some(known_hosts_path) = expand_if_contains_tilde("~/.ssh/known_hosts") {
+        if known_hosts_path.exists() {
+            known_hosts.read_file(known_hosts_path.as_path(), KnownHostFileKind::OpenSSH)?;
+        }
+    }
+
+    match known_hosts.check_port(host_config.host_name.as_str(), host_config.port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "Host key of {} doesn't match ~/.ssh/known_hosts, connection refused",
+                host_config.host_name
+            ),
+        )),
+        CheckResult::NotFound => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "{} is not in ~/.ssh/known_hosts, connect with ssh first to accept its key",
+                host_config.host_name
+            ),
+        )),
+        CheckResult::Failure => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("Can't check host key of {}", host_config.host_name),
+        )),
+    }
+}
+
+// Errors of session (transport) are told apart from SFTP status errors like missing file
+fn sftp_error(err: ssh2::Error) -> io::Error {
+    match err.code() {
+        ErrorCode::Session(LIBSSH2_ERROR_TIMEOUT) => {
+            io::Error::new(io::ErrorKind::TimedOut, err.message().to_string())
+        }
+        ErrorCode::Session(_) => {
+            io::Error::new(io::ErrorKind::ConnectionAborted, err.message().to_string())
+        }
+        ErrorCode::SFTP(_) => err.into(),
+    }
+}
+
+fn is_connection_error(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::TimedOut
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::UnexpectedEof
+    )
+}
+
+fn delete_remote_dir(sftp: &Sftp, path: &Path) -> io::Result<()> {
+    for (entry_path, stat) in sftp.readdir(path).map_err(sftp_error)? {
+        if stat.is_dir() {
+            delete_remote_dir(sftp, entry_path.as_path())?;
+        } else {
+            sftp.unlink(entry_path.as_path()).map_err(sftp_error)?;
+        }
+    }
+    sftp.rmdir(path).map_err(sftp_error)
+}
+
+fn map_remote_entry_to_file_system_item(
+    remote: &RemotePath,
+    entry_path: PathBuf,
+    stat: FileStat,
+    target: Option<PathBuf>,
+    icons: &IconsConfig,
+) -> FileSystemItem {
+    let name = match entry_path.file_name() {
+        Some(name) => name.to_str().unwrap_or("").to_string(),
+        None => return FileSystemItem::Unknown,
+    };
+    // Relative link target is relative to directory of link, not to local working directory
+    let target = target.map(|target| RemotePath {
+        host: remote.host.clone(),
+        path: match entry_path.parent() {
+            Some(parent) if target.is_relative() => parent.join(target),
+            _ => target,
+        },
+    });
+    let path = RemotePath {
+        host: remote.host.clone(),
+        path: entry_path,
+    }
+    .to_path_buf();
+    let modified: DateTime<Local> = Local.timestamp(stat.mtime.unwrap_or(0) as i64, 0);
+    let file_extensions = name.split('.').next_back().unwrap_or("").to_string();
+
+    if stat.file_type().is_symlink() {
+        return FileSystemItem::Symlink(SymlinkItem::new(
+            name.clone(),
+            path.clone(),
+            target.map(|target| target.to_path_buf()).unwrap_or(path),
+            modified,
+            icons.get_file_icon(file_extensions),
+        ));
+    }
+
+    if stat.is_dir() {
+        return FileSystemItem::Directory(DirItem::new(
+            name.clone(),
+            path,
+            modified,
+            icons.get_dir_icon(name),
+            false,
+        ));
+    }
+
+    FileSystemItem::File(FileItem::new(
+        name,
+        path,
+        modified,
+        icons.get_file_icon(file_extensions),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_remote_path() {
+        let remote = RemotePath::parse("sftp://user@host:2222/var/log").unwrap();
+        assert_eq!(remote.host, "user@host:2222");
+        assert_eq!(remote.path, PathBuf::from("/var/log"));
+
+        let remote = RemotePath::parse("sftp://host").unwrap();
+        assert_eq!(remote.host, "host");
+        assert_eq!(remote.path, PathBuf::from("/"));
+    }
+
+    #[test]
+    fn parse_remote_home_path() {
+        let remote = RemotePath::parse("sftp://host/~/projects").unwrap();
+        assert_eq!(remote.path, PathBuf::from("~/projects"));
+        assert_eq!(
+            remote.to_path_buf(),
+            PathBuf::from("sftp://host/~/projects")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_local_and_empty_host() {
+        assert!(RemotePath::parse("/home/user").is_none());
+        assert!(RemotePath::parse("sftp:///etc").is_none());
+        assert!(RemotePath::parse("sftp://").is_none());
+    }
+
+    #[test]
+    fn remote_path_parent() {
+        let parent = RemotePath::parse("sftp://host/var/log").unwrap().parent();
+        assert_eq!(
+            parent.unwrap().to_path_buf(),
+            PathBuf::from("sftp://host/var")
+        );
+
+        let parent = RemotePath::parse("sftp://host/var").unwrap().parent();
+        assert_eq!(parent.unwrap().to_path_buf(), PathBuf::from("sftp://host/"));
+
+        assert!(RemotePath::parse("sftp://host").unwrap().parent().is_none());
+        assert!(RemotePath::parse("sftp://host/~")
+            .unwrap()
+            .parent()
+            .is_none());
+    }
+
+    #[test]
+    fn remote_path_round_trip() {
+        let remote = RemotePath {
+            host: "host".to_string(),
+            path: PathBuf::from("/etc"),
+        };
+        assert_eq!(remote.to_path_buf(), PathBuf::from("sftp://host/etc"));
+        assert_eq!(
+            remote.join("hosts").to_path_buf(),
+            PathBuf::from("sftp://host/etc/hosts")
+        );
+    }
+}
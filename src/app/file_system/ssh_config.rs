@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+
+use super::functions::expand_if_contains_tilde;
+
+#[derive(Clone, Debug)]
+pub struct SshHostConfig {
+    pub host_name: String,
+    pub user: String,
+    pub port: u16,
+    pub identity_files: Vec<PathBuf>,
+}
+
+impl SshHostConfig {
+    pub fn resolve(host: &str, config_content: Option<String>) -> Self {
+        let (user, alias) = match host.split_once('@') {
+            Some((user, alias)) => (Some(user.to_string()), alias),
+            None => (None, host),
+        };
+        let (alias, port) = match alias.split_once(':') {
+            Some((alias, port)) => (alias, port.parse::<u16>().ok()),
+            None => (alias, None),
+        };
+
+        let mut result = SshHostConfig {
+            host_name: alias.to_string(),
+            user: user.clone().unwrap_or_else(default_user),
+            port: port.unwrap_or(22),
+            identity_files: Vec::new(),
+        };
+
+        if let Some(content) = config_content {
+            let mut host_name = None;
+            let mut config_user = None;
+            let mut config_port = None;
+            let mut matches_host = true;
+            for line in content.lines() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+
+                let (keyword, value) =
+                    match line.split_once(|c: char| c.is_whitespace() || c == '=') {
+                        Some((keyword, value)) => (
+                            keyword.to_lowercase(),
+                            value.trim_start_matches(|c: char| c.is_whitespace() || c == '='),
+                        ),
+                        None => continue,
+                    };
+                let value = value.trim().trim_matches('"');
+
+                match keyword.as_str() {
+                    "host" => matches_host = matches_host_patterns(value, alias),
+                    "match" => matches_host = false,
+                    "hostname" if matches_host && host_name.is_none() => {
+                        host_name = Some(value.to_string())
+                    }
+                    "user" if matches_host && config_user.is_none() => {
+                        config_user = Some(value.to_string())
+                    }
+                    "port" if matches_host && config_port.is_none() => {
+                        config_port = value.parse::<u16>().ok()
+                    }
+                    "identityfile" if matches_host => {
+                        if let Some(path) = expand_if_contains_tilde(value) {
+                            result.identity_files.push(path);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(host_name) = host_name {
+                result.host_name = host_name;
+            }
+            if user.is_none() {
+                if let Some(config_user) = config_user {
+                    result.user = config_user;
+                }
+            }
+            if port.is_none() {
+                if let Some(config_port) = config_port {
+                    result.port = config_port;
+                }
+            }
+        }
+
+        for default_key in ["~/.ssh/id_ed25519", "~/.ssh/id_ecdsa", "~/.ssh/id_rsa"].iter() {
+            if let Some(path) = expand_if_contains_tilde(default_key) {
+                if !result.identity_files.contains(&path) {
+                    result.identity_files.push(path);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn default_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "root".to_string())
+}
+
+fn matches_host_patterns(patterns: &str, host: &str) -> bool {
+    let mut matched = false;
+    for pattern in patterns.split_whitespace() {
+        match pattern.strip_prefix('!') {
+            Some(negated) => {
+                if matches_pattern(negated, host) {
+                    return false;
+                }
+            }
+            None => matched = matched || matches_pattern(pattern, host),
+        }
+    }
+
+    matched
+}
+
+fn matches_pattern(pattern: &str, host: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let host: Vec<char> = host.chars().collect();
+    matches_chars(&pattern, &host)
+}
+
+fn matches_chars(pattern: &[char], host: &[char]) -> bool {
+    match pattern.first() {
+        None => host.is_empty(),
+        Some('*') => (0..=host.len()).any(|skip| matches_chars(&pattern[1..], &host[skip..])),
+        Some('?') => !host.is_empty() && matches_chars(&pattern[1..], &host[1..]),
+        Some(c) => host.first() == Some(c) && matches_chars(&pattern[1..], &host[1..]),
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    mem,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{channel, sync_channel, Receiver, TryRecvError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use chardetng::EncodingDetector;
+use encoding_rs::{Decoder, Encoding};
+
+use super::{
+    hex::{is_binary_file, read_chunk},
+    preview::sanitize_line,
+};
+
+// Bigger files are shown partially, from beginning or from end in follow mode
+pub const MAX_VIEW_SIZE: u64 = 64 * 1024 * 1024;
+// Detector is fed with beginning of file only, it is enough to guess
+const DETECT_LENGTH: usize = 64 * 1024;
+// Fast growing file is read in parts, so every update is applied quickly
+const FOLLOW_CHUNK: u64 = 1024 * 1024;
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(250);
+
+// UTF-16 text is full of NUL bytes, but with BOM it can be decoded and viewed as text
+pub fn is_text_file(path: &Path) -> bool {
+    match read_chunk(path, 0, 3) {
+        Ok(start) if Encoding::for_bom(&start).is_some() => true,
+        _ => !is_binary_file(path),
+    }
+}
+
+pub struct ViewerDocument {
+    pub lines: Vec<String>,
+    pub encoding: &'static Encoding,
+    // Only part of file is loaded
+    pub truncated: bool,
+    // Keeps state between chunks so characters split by read are decoded correctly
+    decoder: Decoder,
+    // Position in file up to which content is loaded
+    offset: u64,
+    // Last line has no new line character yet, appended text continues it
+    open_line: bool,
+    // Length of loaded lines, followed file drops first lines over MAX_VIEW_SIZE
+    loaded_size: u64,
+}
+
+pub enum FollowUpdate {
+    // Lines read since last update, first one continues last line when it was open
+    Appended {
+        lines: Vec<String>,
+        open_line: bool,
+        offset: u64,
+    },
+    // File got shorter (eg. rotated log), its end is loaded again
+    Reloaded(ViewerDocument),
+}
+
+impl ViewerDocument {
+    // With from_end whole content of big file is not loaded, only its end (as tail does)
+    pub fn load(path: &Path, from_end: bool) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let file_size = file.metadata()?.len();
+        let start = if from_end && file_size > MAX_VIEW_SIZE {
+            file_size - MAX_VIEW_SIZE
+        } else {
+            0
+        };
+        file.seek(SeekFrom::Start(start))?;
+        let mut content = Vec::new();
+        file.take(MAX_VIEW_SIZE).read_to_end(&mut content)?;
+
+        let (encoding, bom_length) = match Encoding::for_bom(&content) {
+            Some(detected) => detected,
+            None => {
+                let mut detector = EncodingDetector::new();
+                let detect_length = content.len().min(DETECT_LENGTH);
+                detector.feed(&content[..detect_length], detect_length == content.len());
+                (detector.guess(None, true), 0)
+            }
+        };
+
+        // Part of file loaded from middle starts with incomplete line
+        let mut skip = bom_length;
+        if start > 0 {
+            skip = content
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .map(|idx| idx + 1)
+                .unwrap_or(0);
+        }
+
+        let mut document = ViewerDocument {
+            lines: Vec::new(),
+            encoding,
+            truncated: start > 0 || file_size > start + content.len() as u64,
+            decoder: encoding.new_decoder_without_bom_handling(),
+            offset: start + content.len() as u64,
+            open_line: false,
+            loaded_size: 0,
+        };
+        document.append(&content[skip..]);
+
+        Ok(document)
+    }
+
+    pub fn apply(&mut self, update: FollowUpdate) {
+        match update {
+            FollowUpdate::Appended {
+                lines,
+                open_line,
+                offset,
+            } => {
+                for (idx, line) in lines.into_iter().enumerate() {
+                    self.push_line(line, idx == 0 && self.open_line);
+                }
+                self.open_line = open_line;
+                self.offset = offset;
+            }
+            FollowUpdate::Reloaded(document) => *self = document,
+        }
+
+        if self.loaded_size > MAX_VIEW_SIZE {
+            let mut dropped_size = 0;
+            let dropped_count = self
+                .lines
+                .iter()
+                .take_while(|line| {
+                    let keep_going = self.loaded_size - dropped_size > MAX_VIEW_SIZE;
+                    dropped_size += line.len() as u64;
+                    keep_going
+                })
+                .count();
+            self.lines.drain(..dropped_count);
+            self.loaded_size -= dropped_size;
+            self.truncated = true;
+        }
+    }
+
+    // Reading state is moved to follower, document keeps loaded lines
+    fn split_tail(&mut self) -> ViewerDocument {
+        ViewerDocument {
+            lines: Vec::new(),
+            encoding: self.encoding,
+            truncated: self.truncated,
+            decoder: mem::replace(
+                &mut self.decoder,
+                self.encoding.new_decoder_without_bom_handling(),
+            ),
+            offset: self.offset,
+            open_line: self.open_line,
+            loaded_size: 0,
+        }
+    }
+
+    // Loads part of content written to file since last read, loaded lines are taken
+    // out of document. Truncated file (eg. rotated log) is loaded again.
+    fn read_appended(&mut self, path: &Path) -> io::Result<Option<FollowUpdate>> {
+        let mut file = File::open(path)?;
+        let file_size = file.metadata()?.len();
+        if file_size < self.offset {
+            let mut document = ViewerDocument::load(path, true)?;
+            *self = document.split_tail();
+            return Ok(Some(FollowUpdate::Reloaded(document)));
+        }
+        if file_size == self.offset {
+            return Ok(None);
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut content = Vec::new();
+        file.take((file_size - self.offset).min(FOLLOW_CHUNK))
+            .read_to_end(&mut content)?;
+        self.offset += content.len() as u64;
+        self.append(&content);
+
+        Ok(Some(FollowUpdate::Appended {
+            lines: mem::take(&mut self.lines),
+            open_line: self.open_line,
+            offset: self.offset,
+        }))
+    }
+
+    fn push_line(&mut self, line: String, continues_last: bool) {
+        self.loaded_size += line.len() as u64;
+        match self.lines.last_mut() {
+            Some(last_line) if continues_last => last_line.push_str(&line),
+            _ => self.lines.push(line),
+        }
+    }
+
+    fn append(&mut self, bytes: &[u8]) {
+        let mut text = String::with_capacity(
+            self.decoder
+                .max_utf8_buffer_length(bytes.len())
+                .unwrap_or(bytes.len() * 3),
+        );
+        let _ = self.decoder.decode_to_string(bytes, &mut text, false);
+        if text.is_empty() {
+            return;
+        }
+
+        let ends_with_newline = text.ends_with('\n');
+        let mut segments: Vec<&str> = text.split('\n').collect();
+        if ends_with_newline {
+            segments.pop();
+        }
+        for (idx, segment) in segments.iter().enumerate() {
+            self.push_line(sanitize_line(segment), idx == 0 && self.open_line);
+        }
+        self.open_line = !ends_with_newline;
+    }
+}
+
+// Loads document on background thread, big files take a while
+pub struct ViewerLoader {
+    receiver: Receiver<io::Result<ViewerDocument>>,
+}
+
+impl ViewerLoader {
+    pub fn start(path: PathBuf, from_end: bool) -> Self {
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            let _ = sender.send(ViewerDocument::load(&path, from_end));
+        });
+
+        ViewerLoader { receiver }
+    }
+
+    pub fn poll(&self) -> Option<io::Result<ViewerDocument>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(io::Error::other("Failed to load file"))),
+        }
+    }
+}
+
+// Reads content appended to followed file on background thread, reading stops
+// when follower is dropped
+pub struct ViewerFollower {
+    receiver: Receiver<io::Result<FollowUpdate>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ViewerFollower {
+    pub fn start(path: PathBuf, document: &mut ViewerDocument) -> Self {
+        let mut tail = document.split_tail();
+        // Reading waits until interface takes previous update, so memory does not grow
+        let (sender, receiver) = sync_channel(1);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let follow_cancelled = cancelled.clone();
+        thread::spawn(move || {
+            while !follow_cancelled.load(Ordering::Relaxed) {
+                match tail.read_appended(&path) {
+                    Ok(Some(update)) => {
+                        if sender.send(Ok(update)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(None) => thread::sleep(FOLLOW_INTERVAL),
+                    Err(err) => {
+                        let _ = sender.send(Err(err));
+                        return;
+                    }
+                }
+            }
+        });
+
+        ViewerFollower {
+            receiver,
+            cancelled,
+        }
+    }
+
+    pub fn poll(&self) -> Option<io::Result<FollowUpdate>> {
+        match self.receiver.try_recv() {
+            Ok(update) => Some(update),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(io::Error::other("Failed to follow file"))),
+        }
+    }
+}
+
+impl Drop for ViewerFollower {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    fs::Metadata,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{channel, Receiver, TryRecvError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use ignore::{Walk, WalkBuilder};
+
+const BATCH_SIZE: usize = 512;
+const BATCH_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Debug)]
+pub struct WalkEntry {
+    pub path: PathBuf,
+    // Path relative to walked directory
+    pub relative_path: String,
+    pub is_dir: bool,
+}
+
+// Decides on walking thread which entries are sent back
+pub type WalkFilter = Box<dyn Fn(&WalkEntry, &Metadata) -> bool + Send>;
+
+// Walks local directory tree on background thread, walk is stopped when walker is dropped
+pub struct SubtreeWalker {
+    receiver: Receiver<Vec<WalkEntry>>,
+    cancelled: Arc<AtomicBool>,
+    finished: bool,
+}
+
+impl SubtreeWalker {
+    pub fn start(root: PathBuf, respect_gitignore: bool, show_hidden: bool) -> Self {
+        SubtreeWalker::spawn(root, respect_gitignore, show_hidden, None)
+    }
+
+    // Same as start but only entries accepted by filter are returned
+    pub fn start_filtered(
+        root: PathBuf,
+        respect_gitignore: bool,
+        show_hidden: bool,
+        filter: WalkFilter,
+    ) -> Self {
+        SubtreeWalker::spawn(root, respect_gitignore, show_hidden, Some(filter))
+    }
+
+    fn spawn(
+        root: PathBuf,
+        respect_gitignore: bool,
+        show_hidden: bool,
+        filter: Option<WalkFilter>,
+    ) -> Self {
+        let (sender, receiver) = channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let walk_cancelled = cancelled.clone();
+
+        thread::spawn(move || {
+            let walk = build_walk(&root, respect_gitignore, show_hidden);
+
+            let mut batch = Vec::new();
+            let mut last_send = Instant::now();
+            for dir_entry in walk {
+                if walk_cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let dir_entry = match dir_entry {
+                    Ok(dir_entry) => dir_entry,
+                    Err(_) => continue,
+                };
+                if dir_entry.depth() == 0 {
+                    continue;
+                }
+
+                let path = dir_entry.path().to_path_buf();
+                let relative_path = path
+                    .strip_prefix(&root)
+                    .unwrap_or(path.as_path())
+                    .to_str()
+                    .unwrap_or("")
+                    .to_string();
+                let entry = WalkEntry {
+                    is_dir: dir_entry
+                        .file_type()
+                        .map(|file_type| file_type.is_dir())
+                        .unwrap_or(false),
+                    path,
+                    relative_path,
+                };
+                let accepted = match filter {
+                    Some(ref filter) => match dir_entry.metadata() {
+                        Ok(metadata) => filter(&entry, &metadata),
+                        Err(_) => false,
+                    },
+                    None => true,
+                };
+                if accepted {
+                    batch.push(entry);
+                }
+
+                if batch.len() >= BATCH_SIZE
+                    || (!batch.is_empty() && last_send.elapsed() >= BATCH_INTERVAL)
+                {
+                    if sender.send(batch).is_err() {
+                        return;
+                    }
+                    batch = Vec::new();
+                    last_send = Instant::now();
+                }
+            }
+
+            if !batch.is_empty() {
+                let _ = sender.send(batch);
+            }
+        });
+
+        SubtreeWalker {
+            receiver,
+            cancelled,
+            finished: false,
+        }
+    }
+
+    // Returns entries found since last call without blocking
+    pub fn poll(&mut self) -> Vec<WalkEntry> {
+        let mut result = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(mut batch) => result.append(&mut batch),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub fn build_walk(root: &Path, respect_gitignore: bool, show_hidden: bool) -> Walk {
+    WalkBuilder::new(root)
+        .hidden(!show_hidden)
+        .ignore(respect_gitignore)
+        .git_ignore(respect_gitignore)
+        .git_global(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .parents(respect_gitignore)
+        .build()
+}
+
+impl Drop for SubtreeWalker {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,342 @@
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+use std::{
+    fs::Metadata,
+    time::{Duration, SystemTime},
+};
+
+use super::search_filter::{SearchFilter, SearchMode};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemType {
+    Any,
+    File,
+    Directory,
+    Symlink,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PermissionsFilter {
+    // Mode has to be exactly the same
+    Exact(u32),
+    // All given bits have to be set
+    AllOf(u32),
+}
+
+// Criteria of recursive find, every criterion left empty matches everything
+#[derive(Clone, Debug)]
+pub struct FindQuery {
+    name: Option<SearchFilter>,
+    item_type: ItemType,
+    size: (Option<u64>, Option<u64>),
+    age: (Option<Duration>, Option<Duration>),
+    owner: Option<u32>,
+    permissions: Option<PermissionsFilter>,
+}
+
+impl FindQuery {
+    pub fn parse(
+        name: &str,
+        item_type: &str,
+        size: &str,
+        age: &str,
+        owner: &str,
+        permissions: &str,
+    ) -> Result<FindQuery, String> {
+        let name = name.trim();
+        let name = if name.is_empty() {
+            None
+        } else {
+            let filter = SearchFilter::new(SearchMode::Glob, name);
+            if let Some(error) = filter.error {
+                return Err(format!("Name: {}", error));
+            }
+            Some(filter)
+        };
+
+        let item_type = match item_type.trim().to_lowercase().as_str() {
+            "" => ItemType::Any,
+            "f" | "file" => ItemType::File,
+            "d" | "dir" | "directory" => ItemType::Directory,
+            "l" | "link" | "symlink" => ItemType::Symlink,
+            _ => return Err("Type: expected f, d or l".to_string()),
+        };
+
+        let size = parse_range(size, parse_size)
+            .ok_or_else(|| "Size: expected MIN..MAX eg. 10k..2M".to_string())?;
+        let age = parse_range(age, parse_age)
+            .ok_or_else(|| "Modified: expected MIN..MAX eg. ..7d or 1h..2w".to_string())?;
+
+        let owner = owner.trim();
+        let owner = if owner.is_empty() {
+            None
+        } else {
+            Some(parse_owner(owner).ok_or_else(|| format!("Owner: unknown user {}", owner))?)
+        };
+
+        let permissions = permissions.trim();
+        let permissions =
+            if permissions.is_empty() {
+                None
+            } else {
+                Some(parse_permissions(permissions).ok_or_else(|| {
+                    "Permissions: expected octal mode eg. 644 or +111".to_string()
+                })?)
+            };
+        if !cfg!(unix) && (owner.is_some() || permissions.is_some()) {
+            return Err("Owner and permissions can be checked only on unix".to_string());
+        }
+
+        Ok(FindQuery {
+            name,
+            item_type,
+            size,
+            age,
+            owner,
+            permissions,
+        })
+    }
+
+    pub fn matches(&self, file_name: &str, metadata: &Metadata) -> bool {
+        if let Some(ref name) = self.name {
+            if name.rank(file_name).is_none() {
+                return false;
+            }
+        }
+
+        let file_type = metadata.file_type();
+        let type_matches = match self.item_type {
+            ItemType::Any => true,
+            ItemType::File => file_type.is_file(),
+            ItemType::Directory => file_type.is_dir(),
+            ItemType::Symlink => file_type.is_symlink(),
+        };
+        if !type_matches {
+            return false;
+        }
+
+        if !in_range(metadata.len(), self.size) {
+            return false;
+        }
+
+        if self.age.0.is_some() || self.age.1.is_some() {
+            let age = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .unwrap_or_default();
+            if !in_range(age, self.age) {
+                return false;
+            }
+        }
+
+        self.matches_owner_and_permissions(metadata)
+    }
+
+    #[cfg(unix)]
+    fn matches_owner_and_permissions(&self, metadata: &Metadata) -> bool {
+        if let Some(owner) = self.owner {
+            if metadata.uid() != owner {
+                return false;
+            }
+        }
+
+        let mode = metadata.mode() & 0o7777;
+        match self.permissions {
+            Some(PermissionsFilter::Exact(permissions)) => mode == permissions,
+            Some(PermissionsFilter::AllOf(permissions)) => mode & permissions == permissions,
+            None => true,
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn matches_owner_and_permissions(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+}
+
+fn in_range<T: PartialOrd>(value: T, range: (Option<T>, Option<T>)) -> bool {
+    match range {
+        (Some(min), _) if value < min => false,
+        (_, Some(max)) if value > max => false,
+        _ => true,
+    }
+}
+
+// Empty input is unbounded range, otherwise MIN..MAX where any side can be omitted
+fn parse_range<T, F: Fn(&str) -> Option<T>>(
+    input: &str,
+    parse_value: F,
+) -> Option<(Option<T>, Option<T>)> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Some((None, None));
+    }
+
+    let (min, max) = input.split_at(input.find("..")?);
+    let max = &max[2..];
+    let min = match min.trim() {
+        "" => None,
+        min => Some(parse_value(min)?),
+    };
+    let max = match max.trim() {
+        "" => None,
+        max => Some(parse_value(max)?),
+    };
+
+    Some((min, max))
+}
+
+fn split_unit(input: &str) -> (&str, &str) {
+    let unit_start = input
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    input.split_at(unit_start)
+}
+
+// Size in bytes with optional k, M, G or T suffix (powers of 1024)
+fn parse_size(input: &str) -> Option<u64> {
+    let (value, unit) = split_unit(input);
+    let value: f64 = value.trim().parse().ok()?;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    let multiplier: u64 = match unit.to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1 << 10,
+        "m" | "mb" => 1 << 20,
+        "g" | "gb" => 1 << 30,
+        "t" | "tb" => 1 << 40,
+        _ => return None,
+    };
+
+    Some((value * multiplier as f64) as u64)
+}
+
+// Time since last modification with s, m, h, d or w suffix (days by default)
+fn parse_age(input: &str) -> Option<Duration> {
+    let (value, unit) = split_unit(input);
+    // Negative, infinite or too big values can't be turned into duration
+    let value: f64 = value.trim().parse().ok()?;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    let seconds: u64 = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "" | "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(value * seconds as f64).ok()
+}
+
+fn parse_permissions(input: &str) -> Option<PermissionsFilter> {
+    let (all_of, mode) = match input.strip_prefix('+') {
+        Some(mode) => (true, mode),
+        None => (false, input),
+    };
+    let mode = u32::from_str_radix(mode, 8).ok()?;
+    if mode > 0o7777 {
+        return None;
+    }
+
+    if all_of {
+        Some(PermissionsFilter::AllOf(mode))
+    } else {
+        Some(PermissionsFilter::Exact(mode))
+    }
+}
+
+#[cfg(unix)]
+fn parse_owner(input: &str) -> Option<u32> {
+    if let Ok(uid) = input.parse::<u32>() {
+        return Some(uid);
+    }
+
+    let name = std::ffi::CString::new(input).ok()?;
+    // getpwnam returns pointer to static storage, uid is copied out right away
+    let passwd = unsafe { libc::getpwnam(name.as_ptr()) };
+    if passwd.is_null() {
+        None
+    } else {
+        Some(unsafe { (*passwd).pw_uid })
+    }
+}
+
+#[cfg(not(unix))]
+fn parse_owner(input: &str) -> Option<u32> {
+    input.parse::<u32>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_age_accepts_units() {
+        assert_eq!(parse_age("30s"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_age("2h"), Some(Duration::from_secs(2 * 60 * 60)));
+        assert_eq!(parse_age("1"), Some(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!(
+            parse_age("0.5w"),
+            Some(Duration::from_secs(7 * 12 * 60 * 60))
+        );
+        assert_eq!(parse_age("3x"), None);
+    }
+
+    #[test]
+    fn parse_age_rejects_values_out_of_duration_range() {
+        assert_eq!(parse_age("-1d"), None);
+        assert_eq!(parse_age("inf"), None);
+        assert_eq!(parse_age("nan"), None);
+        assert_eq!(parse_age("99999999999999999999w"), None);
+    }
+
+    #[test]
+    fn parse_size_accepts_units() {
+        assert_eq!(parse_size("10"), Some(10));
+        assert_eq!(parse_size("10k"), Some(10 * 1024));
+        assert_eq!(parse_size("1.5M"), Some(1024 * 1024 * 3 / 2));
+        assert_eq!(parse_size("-1k"), None);
+        assert_eq!(parse_size("nan"), None);
+    }
+
+    #[test]
+    fn parse_range_allows_open_sides() {
+        assert_eq!(parse_range("", parse_size), Some((None, None)));
+        assert_eq!(parse_range("..2k", parse_size), Some((None, Some(2048))));
+        assert_eq!(parse_range("1k..", parse_size), Some((Some(1024), None)));
+        assert_eq!(parse_range("1k", parse_size), None);
+    }
+
+    #[test]
+    fn parse_permissions_modes() {
+        assert_eq!(
+            parse_permissions("644"),
+            Some(PermissionsFilter::Exact(0o644))
+        );
+        assert_eq!(
+            parse_permissions("+111"),
+            Some(PermissionsFilter::AllOf(0o111))
+        );
+        assert_eq!(parse_permissions("888"), None);
+        assert_eq!(parse_permissions("17777"), None);
+    }
+
+    #[test]
+    fn invalid_age_is_reported_as_modified_error() {
+        let error = FindQuery::parse("", "", "", "-1d..", "", "").unwrap_err();
+        assert!(error.starts_with("Modified:"));
+        let error = FindQuery::parse("", "", "", "..99999999999999999999w", "", "").unwrap_err();
+        assert!(error.starts_with("Modified:"));
+    }
+
+    #[test]
+    fn invalid_type_is_reported() {
+        assert!(FindQuery::parse("", "x", "", "", "", "").is_err());
+        assert!(FindQuery::parse("*.rs", "f", "1k..", "..7d", "", "").is_ok());
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+// Same aging threshold as z and zoxide, when sum of ranks exceeds it all ranks decay
+const MAX_TOTAL_RANK: f64 = 10000.0;
+
+#[derive(Clone, Debug)]
+struct FrecencyEntry {
+    rank: f64,
+    last_access: u64,
+}
+
+impl FrecencyEntry {
+    fn score(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_access);
+        if age < HOUR {
+            self.rank * 4.0
+        } else if age < DAY {
+            self.rank * 2.0
+        } else if age < WEEK {
+            self.rank * 0.5
+        } else {
+            self.rank * 0.25
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FrecencyDb {
+    entries: HashMap<PathBuf, FrecencyEntry>,
+    file_path: Option<PathBuf>,
+}
+
+impl FrecencyDb {
+    pub fn load() -> Self {
+        let file_path = dirs::data_dir().map(|data_dir| data_dir.join("sfm").join("frecency"));
+        let mut db = FrecencyDb {
+            entries: HashMap::new(),
+            file_path: file_path.clone(),
+        };
+        if let Some(content) = file_path.and_then(|path| fs::read_to_string(path).ok()) {
+            db.merge_z_format(&content);
+        }
+        // Removed directories are dropped once here instead of checking them on every query
+        db.entries.retain(|path, _| path.is_dir());
+
+        db
+    }
+
+    // Only in memory, database is saved on exit
+    pub fn add<TPath: AsRef<Path>>(&mut self, path: TPath) {
+        let now = now();
+        let entry = self
+            .entries
+            .entry(path.as_ref().to_path_buf())
+            .or_insert(FrecencyEntry {
+                rank: 0.0,
+                last_access: now,
+            });
+        entry.rank += 1.0;
+        entry.last_access = now;
+        self.age();
+    }
+
+    pub fn query(&self, phrase: &str, exclude: &Path, limit: usize) -> Vec<PathBuf> {
+        let keywords: Vec<String> = phrase
+            .split_whitespace()
+            .map(|keyword| keyword.to_lowercase())
+            .collect();
+        let now = now();
+        let mut matches: Vec<(f64, &PathBuf)> = self
+            .entries
+            .iter()
+            .filter(|(path, _)| path.as_path() != exclude && matches_keywords(path, &keywords))
+            .map(|(path, entry)| (entry.score(now), path))
+            .collect();
+        matches.sort_by(|(left_score, _), (right_score, _)| {
+            right_score
+                .partial_cmp(left_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, path)| path.clone())
+            .collect()
+    }
+
+    pub fn import_z<TPath: AsRef<Path>>(&mut self, z_file: TPath) -> io::Result<usize> {
+        let content = fs::read_to_string(z_file)?;
+        let imported = self.merge_z_format(&content);
+        self.save()?;
+
+        Ok(imported)
+    }
+
+    pub fn import_zoxide(&mut self) -> io::Result<usize> {
+        let output = Command::new("zoxide")
+            .args(["query", "--list", "--score"])
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        let now = now();
+        let mut imported = 0;
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            if let Some((score, path)) = line.trim().split_once(' ') {
+                let path = path.trim();
+                if let Some(score) = parse_rank(score.trim()).filter(|_| !path.is_empty()) {
+                    self.merge_entry(PathBuf::from(path), score, now);
+                    imported += 1;
+                }
+            }
+        }
+        self.age();
+        self.save()?;
+
+        Ok(imported)
+    }
+
+    fn merge_z_format(&mut self, content: &str) -> usize {
+        let mut imported = 0;
+        for line in content.lines() {
+            let mut parts = line.rsplitn(3, '|');
+            if let (Some(last_access), Some(rank), Some(path)) = (
+                parts.next(),
+                parts.next(),
+                parts.next().filter(|path| !path.is_empty()),
+            ) {
+                if let (Ok(last_access), Some(rank)) =
+                    (last_access.parse::<u64>(), parse_rank(rank))
+                {
+                    self.merge_entry(PathBuf::from(path), rank, last_access);
+                    imported += 1;
+                }
+            }
+        }
+
+        imported
+    }
+
+    fn merge_entry(&mut self, path: PathBuf, rank: f64, last_access: u64) {
+        let entry = self.entries.entry(path).or_insert(FrecencyEntry {
+            rank: 0.0,
+            last_access,
+        });
+        entry.rank += rank;
+        entry.last_access = entry.last_access.max(last_access);
+    }
+
+    fn age(&mut self) {
+        let total_rank: f64 = self.entries.values().map(|entry| entry.rank).sum();
+        if total_rank > MAX_TOTAL_RANK {
+            let factor = 0.9 * MAX_TOTAL_RANK / total_rank;
+            for entry in self.entries.values_mut() {
+                entry.rank *= factor;
+            }
+            self.entries.retain(|_, entry| entry.rank >= 1.0);
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let file_path = match self.file_path {
+            Some(ref file_path) => file_path,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "can't find data directory",
+                ))
+            }
+        };
+
+        let mut content = String::new();
+        for (path, entry) in self.entries.iter() {
+            content.push_str(&format!(
+                "{}|{}|{}\n",
+                path.to_str().unwrap_or(""),
+                entry.rank,
+                entry.last_access
+            ));
+        }
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(file_path, content)
+    }
+}
+
+// Keywords have to appear in path in given order and last one has to match last path component
+fn matches_keywords(path: &Path, keywords: &[String]) -> bool {
+    let path_str = path.to_str().unwrap_or("").to_lowercase();
+    let mut search_from = 0;
+    for keyword in keywords.iter() {
+        match path_str[search_from..].find(keyword.as_str()) {
+            Some(idx) => search_from += idx + keyword.len(),
+            None => return false,
+        }
+    }
+
+    match keywords.last() {
+        Some(last_keyword) => path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.to_lowercase().contains(last_keyword.as_str()))
+            .unwrap_or(false),
+        None => true,
+    }
+}
+
+// Infinite or NaN rank would break aging of whole database
+fn parse_rank(rank: &str) -> Option<f64> {
+    rank.parse::<f64>()
+        .ok()
+        .filter(|rank| rank.is_finite() && *rank > 0.0)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_z_format_reads_valid_lines() {
+        let mut db = FrecencyDb::default();
+        let imported = db.merge_z_format("/home/user/src|12.5|1000\n/tmp/a|b|2|2000\n");
+        assert_eq!(imported, 2);
+        assert_eq!(db.entries[Path::new("/home/user/src")].rank, 12.5);
+        assert_eq!(db.entries[Path::new("/home/user/src")].last_access, 1000);
+        assert_eq!(db.entries[Path::new("/tmp/a|b")].rank, 2.0);
+    }
+
+    #[test]
+    fn merge_z_format_skips_malformed_lines() {
+        let mut db = FrecencyDb::default();
+        let content = "no separators\n/tmp|1\n/tmp|x|1\n/tmp|1|x\n|5|100\n\
+                       /tmp|inf|1\n/tmp|NaN|1\n/tmp|-3|1\n";
+        assert_eq!(db.merge_z_format(content), 0);
+        assert!(db.entries.is_empty());
+    }
+
+    #[test]
+    fn merge_entry_sums_ranks_and_keeps_last_access() {
+        let mut db = FrecencyDb::default();
+        db.merge_z_format("/tmp|2|3000\n/tmp|3|1000\n");
+        let entry = &db.entries[Path::new("/tmp")];
+        assert_eq!(entry.rank, 5.0);
+        assert_eq!(entry.last_access, 3000);
+    }
+
+    #[test]
+    fn score_decays_with_age() {
+        let entry = FrecencyEntry {
+            rank: 8.0,
+            last_access: 0,
+        };
+        assert_eq!(entry.score(HOUR - 1), 32.0);
+        assert_eq!(entry.score(DAY - 1), 16.0);
+        assert_eq!(entry.score(WEEK - 1), 4.0);
+        assert_eq!(entry.score(WEEK), 2.0);
+        // Access time in future (eg. clock change) counts as recent
+        assert_eq!(entry.score(0), 32.0);
+    }
+
+    #[test]
+    fn age_scales_ranks_and_drops_rare_entries() {
+        let mut db = FrecencyDb::default();
+        db.merge_z_format("/often|19000|1\n/rare|1|1\n");
+        db.age();
+        let total: f64 = db.entries.values().map(|entry| entry.rank).sum();
+        assert!((total - 0.9 * MAX_TOTAL_RANK).abs() < 1.0);
+        assert!(db.entries.contains_key(Path::new("/often")));
+        assert!(!db.entries.contains_key(Path::new("/rare")));
+    }
+
+    #[test]
+    fn keywords_match_in_order_and_last_in_file_name() {
+        let keywords = |phrase: &str| -> Vec<String> {
+            phrase
+                .split_whitespace()
+                .map(|keyword| keyword.to_lowercase())
+                .collect()
+        };
+        let path = Path::new("/home/user/Projects/sfm");
+        assert!(matches_keywords(path, &keywords("proj sfm")));
+        assert!(matches_keywords(path, &keywords("SFM")));
+        assert!(matches_keywords(path, &[]));
+        assert!(!matches_keywords(path, &keywords("sfm proj")));
+        assert!(!matches_keywords(path, &keywords("proj")));
+    }
+
+    #[test]
+    fn query_orders_by_score_and_excludes_current_dir() {
+        let mut db = FrecencyDb::default();
+        let now = now();
+        db.merge_entry(PathBuf::from("/a/src"), 1.0, now);
+        db.merge_entry(PathBuf::from("/b/src"), 5.0, now);
+        db.merge_entry(PathBuf::from("/c/src"), 3.0, now);
+        assert_eq!(
+            db.query("src", Path::new("/c/src"), 10),
+            vec![PathBuf::from("/b/src"), PathBuf::from("/a/src")]
+        );
+        assert_eq!(
+            db.query("src", Path::new("/"), 1),
+            vec![PathBuf::from("/b/src")]
+        );
+    }
+}
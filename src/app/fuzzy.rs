@@ -0,0 +1,156 @@
+const SCORE_MATCH: i64 = 16;
+const BONUS_BOUNDARY: i64 = 10;
+const BONUS_CAMEL_CASE: i64 = 8;
+const BONUS_CONSECUTIVE: i64 = 6;
+const BONUS_FILE_NAME: i64 = 4;
+const PENALTY_GAP_START: i64 = 3;
+const PENALTY_GAP_EXTENSION: i64 = 1;
+
+#[derive(Clone, Debug, Default)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    // Char indices of matched characters in text
+    pub positions: Vec<usize>,
+}
+
+// Pattern characters have to appear in text in given order, pattern is case
+// insensitive unless it contains upper case letter (smart case)
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let case_sensitive = pattern.chars().any(|c| c.is_uppercase());
+    let normalize = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(normalize)
+        .collect();
+    if pattern.is_empty() {
+        return Some(FuzzyMatch::default());
+    }
+
+    let text: Vec<char> = text.chars().collect();
+
+    // First occurrence of whole pattern gives end of match
+    let mut pattern_idx = 0;
+    let mut end = None;
+    for (idx, c) in text.iter().enumerate() {
+        if normalize(*c) == pattern[pattern_idx] {
+            pattern_idx += 1;
+            if pattern_idx == pattern.len() {
+                end = Some(idx);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // Going backwards from end gives shortest window containing pattern
+    let mut pattern_idx = pattern.len();
+    let mut start = end;
+    for idx in (0..=end).rev() {
+        if normalize(text[idx]) == pattern[pattern_idx - 1] {
+            pattern_idx -= 1;
+            if pattern_idx == 0 {
+                start = idx;
+                break;
+            }
+        }
+    }
+
+    let file_name_start = text
+        .iter()
+        .rposition(|c| *c == '/')
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    let mut result = FuzzyMatch::default();
+    let mut pattern_idx = 0;
+    let mut previous: Option<usize> = None;
+    for idx in start..=end {
+        if pattern_idx == pattern.len() {
+            break;
+        }
+        if normalize(text[idx]) != pattern[pattern_idx] {
+            continue;
+        }
+
+        result.score += SCORE_MATCH;
+        if idx == 0 || is_separator(text[idx - 1]) {
+            result.score += BONUS_BOUNDARY;
+        } else if text[idx - 1].is_lowercase() && text[idx].is_uppercase() {
+            result.score += BONUS_CAMEL_CASE;
+        }
+        if idx >= file_name_start {
+            result.score += BONUS_FILE_NAME;
+        }
+        if let Some(previous) = previous {
+            let gap = (idx - previous - 1) as i64;
+            if gap == 0 {
+                result.score += BONUS_CONSECUTIVE;
+            } else {
+                result.score -= PENALTY_GAP_START + (gap - 1) * PENALTY_GAP_EXTENSION;
+            }
+        }
+
+        result.positions.push(idx);
+        previous = Some(idx);
+        pattern_idx += 1;
+    }
+
+    Some(result)
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '/' | '\\' | '_' | '-' | '.' | ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_scores_boundaries_and_gaps() {
+        // Every char starts a word, two gaps of one char are penalized
+        let result = fuzzy_match("abc", "a_b_c").unwrap();
+        assert_eq!(result.positions, vec![0, 2, 4]);
+        assert_eq!(result.score, 84);
+
+        let consecutive = fuzzy_match("main", "src/main.rs").unwrap();
+        let scattered = fuzzy_match("main", "mxaxixn").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_file_name() {
+        let in_file_name = fuzzy_match("lib", "src/lib.rs").unwrap();
+        let in_dir_name = fuzzy_match("lib", "lib/src.rs").unwrap();
+        assert!(in_file_name.score > in_dir_name.score);
+    }
+
+    #[test]
+    fn fuzzy_match_uses_shortest_window() {
+        let result = fuzzy_match("ab", "axxab").unwrap();
+        assert_eq!(result.positions, vec![3, 4]);
+    }
+
+    #[test]
+    fn fuzzy_match_uses_smart_case_and_ignores_whitespace() {
+        assert!(fuzzy_match("readme", "README.md").is_some());
+        assert!(fuzzy_match("ReadMe", "readme.md").is_none());
+        assert_eq!(
+            fuzzy_match("ma in", "main.rs").unwrap().positions,
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(fuzzy_match("", "main.rs").unwrap().score, 0);
+    }
+
+    #[test]
+    fn fuzzy_match_rejects_missing_or_reordered_chars() {
+        assert!(fuzzy_match("xyz", "main.rs").is_none());
+        assert!(fuzzy_match("ba", "ab").is_none());
+    }
+}
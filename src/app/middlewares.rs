@@ -2,11 +2,68 @@ use super::{
     actions::{
         AppAction, DirectoryAction, FileAction, FileManagerActions, PanelInfo, SymlinkAction,
     },
-    file_system::FileSystem,
+    file_system::{sftp_file_system::RemotePath, FileSystem},
     state::{AppState, ModalType},
 };
 use crate::core::store::Store;
-use std::{fmt::Debug, fs};
+use std::{fmt::Debug, path::PathBuf};
+
+pub fn read_only_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
+    store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    action: FileManagerActions,
+) -> Option<FileManagerActions> {
+    let modified_paths = match action.clone() {
+        FileManagerActions::File(file_action) => match file_action {
+            FileAction::Delete { panel } => Some(("delete", vec![panel.path])),
+            FileAction::Rename { from, to } => Some(("rename", vec![from.path, to.path])),
+            FileAction::Copy { to, .. } => Some(("copy to", vec![to.path])),
+            FileAction::Move { from, to } => Some(("move", vec![from.path, to.path])),
+            FileAction::Create { file_name, panel } => {
+                Some(("create", vec![panel.path.join(file_name)]))
+            }
+            FileAction::Open { .. } | FileAction::OpenAtLine { .. } => None,
+        },
+        FileManagerActions::Directory(dir_action) => match dir_action {
+            DirectoryAction::DeleteWithContent { panel } => Some(("delete", vec![panel.path])),
+            DirectoryAction::Delete { panel, .. } => Some(("delete", vec![panel.path])),
+            DirectoryAction::Rename { from, to } => Some(("rename", vec![from.path, to.path])),
+            DirectoryAction::Copy { to, .. } => Some(("copy to", vec![to.path])),
+            DirectoryAction::Move { from, to } => Some(("move", vec![from.path, to.path])),
+            DirectoryAction::Create { dir_name, panel } => {
+                Some(("create", vec![panel.path.join(dir_name)]))
+            }
+            DirectoryAction::Open { .. } => None,
+        },
+        FileManagerActions::Symlink(symlink_action) => match symlink_action {
+            SymlinkAction::Delete { panel } => Some(("delete", vec![panel.path])),
+            // Link target is only pointed to, it may be in read-only location
+            SymlinkAction::Create { symlink_path, .. } => {
+                Some(("create symlink", vec![symlink_path]))
+            }
+            SymlinkAction::Open { .. } => None,
+        },
+        _ => None,
+    };
+
+    if let Some((operation, paths)) = modified_paths {
+        let state = store.get_state();
+        let read_only_paths: Vec<PathBuf> = paths
+            .into_iter()
+            .filter(|path| state.config.read_only.is_read_only(path))
+            .collect();
+        if let Some(path) = read_only_paths.first() {
+            return Some(FileManagerActions::App(AppAction::ShowModal(
+                ModalType::ErrorModal(format!(
+                    "Read-only mode: can't {} \n {}",
+                    operation,
+                    path.to_str().unwrap_or("")
+                )),
+            )));
+        }
+    }
+
+    Some(action)
+}
 
 pub fn symlink_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
     store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
@@ -19,13 +76,14 @@ pub fn symlink_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
 }
 
 fn symlink_resolver<TFileSystem: Clone + Debug + Default + FileSystem>(
-    _: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
     symlink_action: SymlinkAction,
 ) -> Option<FileManagerActions> {
+    let file_system = &store.get_state().file_system;
     match symlink_action {
-        SymlinkAction::Open { panel, in_new_tab } => match fs::read_link(panel.path.as_path()) {
+        SymlinkAction::Open { panel, in_new_tab } => match file_system.read_link(&panel.path) {
             Ok(link_path) => {
-                if link_path.is_dir() {
+                if file_system.get_dir_info(&link_path).is_some() {
                     Some(FileManagerActions::Directory(DirectoryAction::Open {
                         panel: PanelInfo {
                             path: link_path,
@@ -52,6 +110,24 @@ fn symlink_resolver<TFileSystem: Clone + Debug + Default + FileSystem>(
     }
 }
 
+pub fn file_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
+    _: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    action: FileManagerActions,
+) -> Option<FileManagerActions> {
+    match action {
+        // Program is started with local path, remote file would have to be downloaded first
+        FileManagerActions::File(FileAction::Open { ref panel })
+        | FileManagerActions::File(FileAction::OpenAtLine { ref panel, .. })
+            if RemotePath::parse(&panel.path).is_some() =>
+        {
+            Some(FileManagerActions::App(AppAction::ShowModal(
+                ModalType::ErrorModal("Opening remote files is not supported".to_string()),
+            )))
+        }
+        _ => Some(action),
+    }
+}
+
 pub fn dir_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
     store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
     action: FileManagerActions,
@@ -63,10 +139,30 @@ pub fn dir_middleware<TFileSystem: Clone + Debug + Default + FileSystem>(
 }
 
 fn directory_resolver<TFileSystem: Clone + Debug + Default + FileSystem>(
-    _: &mut Store<AppState<TFileSystem>, FileManagerActions>,
+    store: &mut Store<AppState<TFileSystem>, FileManagerActions>,
     dir_action: DirectoryAction,
 ) -> Option<FileManagerActions> {
     match dir_action {
+        DirectoryAction::Open { panel, in_new_tab } => {
+            if store
+                .get_state()
+                .file_system
+                .get_dir_info(&panel.path)
+                .is_some()
+            {
+                Some(FileManagerActions::Directory(DirectoryAction::Open {
+                    panel,
+                    in_new_tab,
+                }))
+            } else {
+                Some(FileManagerActions::App(AppAction::ShowModal(
+                    ModalType::ErrorModal(format!(
+                        "Can't open directory \n {}",
+                        panel.path.to_str().unwrap_or("")
+                    )),
+                )))
+            }
+        }
         DirectoryAction::Delete { panel, is_empty } => {
             if is_empty {
                 Some(FileManagerActions::Directory(DirectoryAction::Delete {
@@ -1,7 +1,15 @@
 pub mod actions;
+pub mod bookmarks;
+pub mod command_line;
 pub mod components;
 pub mod config;
 pub mod file_system;
+pub mod find_query;
+pub mod frecency;
+pub mod fuzzy;
 pub mod middlewares;
 pub mod reducers;
+pub mod search_filter;
+pub mod session;
 pub mod state;
+pub mod syntax;
@@ -0,0 +1,50 @@
+use std::{fmt::Debug, path::PathBuf};
+
+use crate::app::{
+    actions::BookmarkAction,
+    file_system::FileSystem,
+    state::{AppState, ModalType},
+};
+
+pub fn bookmark_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
+    state: AppState<TFileSystem>,
+    bookmark_action: BookmarkAction,
+) -> AppState<TFileSystem> {
+    match bookmark_action {
+        BookmarkAction::Add { key, path } => add_bookmark(state, key, path),
+        BookmarkAction::Delete { key } => delete_bookmark(state, key),
+    }
+}
+
+fn add_bookmark<TFileSystem: Clone + Debug + Default + FileSystem>(
+    mut state: AppState<TFileSystem>,
+    key: char,
+    path: PathBuf,
+) -> AppState<TFileSystem> {
+    match state.bookmarks.set(key, path) {
+        Ok(_) => state,
+        Err(err) => AppState {
+            modal: Some(ModalType::ErrorModal(format!(
+                "Can't save bookmark '{}' \n {}",
+                key, err
+            ))),
+            ..state
+        },
+    }
+}
+
+fn delete_bookmark<TFileSystem: Clone + Debug + Default + FileSystem>(
+    mut state: AppState<TFileSystem>,
+    key: char,
+) -> AppState<TFileSystem> {
+    match state.bookmarks.remove(key) {
+        Ok(_) => state,
+        Err(err) => AppState {
+            modal: Some(ModalType::ErrorModal(format!(
+                "Can't delete bookmark '{}' \n {}",
+                key, err
+            ))),
+            ..state
+        },
+    }
+}
@@ -1,7 +1,7 @@
 use crate::app::{
     actions::{DirectoryAction, PanelInfo, PanelSide},
     config::icon_cfg::IconsConfig,
-    file_system::FileSystem,
+    file_system::{sftp_file_system::RemotePath, FileSystem},
     state::{AppState, PanelState, TabIdx, TabState},
 };
 use std::fmt::Debug;
@@ -187,10 +187,16 @@ fn create_directory<TFileSystem: Clone + Debug + Default + FileSystem>(
 }
 
 fn open_dir<TFileSystem: Clone + Debug + Default + FileSystem>(
-    state: AppState<TFileSystem>,
+    mut state: AppState<TFileSystem>,
     panel: PanelInfo,
     in_new_tab: bool,
 ) -> AppState<TFileSystem> {
+    if RemotePath::parse(&panel.path).is_none() {
+        if let Some(dir_info) = state.file_system.get_dir_info(&panel.path) {
+            state.frecency.add(dir_info.path);
+        }
+    }
+
     match panel.side {
         PanelSide::Left => AppState {
             left_panel: PanelState {
@@ -363,11 +369,7 @@ fn copy_dir_to_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     for (idx, tab_state) in tabs.iter_mut().enumerate() {
         if idx == current_tab {
             match file_system.copy_dir(from.as_path(), to.as_path()) {
-                Ok(_) => result.push(TabState::with_dir(
-                    tab_state.path.as_path(),
-                    file_system,
-                    icons,
-                )),
+                Ok(_) => result.push(tab_state.reload(file_system, icons)),
                 Err(_) => {}
             }
         } else {
@@ -394,17 +396,11 @@ fn open_dir_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
                 .iter()
                 .find(|item| item.is_dir() && item.get_path().eq(path.as_path()));
             if let Some(item) = dir_to_open {
-                result.push(TabState::with_dir(
-                    item.get_path().as_path(),
-                    file_system,
-                    icons,
-                ));
+                result.push(val.navigate_to(item.get_path().as_path(), file_system, icons));
+            } else if file_system.get_dir_info(&path).is_some() {
+                result.push(val.navigate_to(path.as_path(), file_system, icons));
             } else {
-                if path.exists() {
-                    result.push(TabState::with_dir(path.as_path(), file_system, icons));
-                } else {
-                    result.push(val.clone());
-                }
+                result.push(val.clone());
             }
         } else {
             result.push(val.clone());
@@ -427,17 +423,11 @@ fn rename_dir_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     for (idx, tab_state) in tabs.iter().enumerate() {
         if idx == current_tab {
             match file_system.rename_item(&from, &to) {
-                Ok(_) => result.push(TabState::with_dir(
-                    tab_state.path.as_path(),
-                    file_system,
-                    icons,
-                )),
-                Err(_) => result.push(TabState::with_dir(
+                Ok(_) => result.push(tab_state.reload(file_system, icons)),
+                Err(_) => result.push(
                     //TODO: temporary fix add proper error handling in reducers
-                    tab_state.path.as_path(),
-                    file_system,
-                    icons,
-                )),
+                    tab_state.reload(file_system, icons),
+                ),
             }
         } else {
             result.push(tab_state.clone());
@@ -464,9 +454,7 @@ fn delete_dir_from_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
                 .find(|item| item.is_dir() && item.get_path().eq(path.as_path()));
             if let Some(item) = dir_to_delete {
                 match file_system.delete_empty_dir(&item.get_path()) {
-                    Ok(_) => {
-                        result.push(TabState::with_dir(val.path.as_path(), file_system, icons))
-                    }
+                    Ok(_) => result.push(val.reload(file_system, icons)),
                     Err(_) => {}
                 }
             } else {
@@ -497,9 +485,7 @@ fn delete_dir_with_content_from_tab<TFileSystem: Clone + Debug + Default + FileS
                 .find(|item| item.is_dir() && item.get_path().eq(path.as_path()));
             if let Some(item) = dir_to_delete {
                 match file_system.delete_dir(&item.get_path()) {
-                    Ok(_) => {
-                        result.push(TabState::with_dir(val.path.as_path(), file_system, icons))
-                    }
+                    Ok(_) => result.push(val.reload(file_system, icons)),
                     Err(_) => {}
                 }
             } else {
@@ -523,16 +509,12 @@ fn create_directory_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     let mut result = Vec::<TabState<TFileSystem>>::new();
     for (idx, val) in tabs.iter_mut().enumerate() {
         if idx == tab {
-            if parent_path.exists() {
+            if file_system.get_dir_info(&parent_path).is_some() {
                 let mut dir_path = parent_path.clone();
                 dir_path.push(dir_name.clone());
 
                 match file_system.create_dir(&dir_path) {
-                    Ok(_) => result.push(TabState::with_dir(
-                        parent_path.as_path(),
-                        file_system,
-                        icons,
-                    )),
+                    Ok(_) => result.push(val.reload(file_system, icons)),
                     Err(_) => {}
                 };
             } else {
@@ -1,11 +1,14 @@
 use std::fmt::Debug;
-use std::{ffi::OsStr, path::PathBuf};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
 
 use crate::app::{
     actions::{FileAction, PanelInfo, PanelSide},
     config::{icon_cfg::IconsConfig, program_associations::FileAssociatedPrograms},
-    file_system::{file_system_item::FileSystemItem, FileSystem},
-    state::{AppState, ChildProgramDesc, PanelState, TabIdx, TabState},
+    file_system::{file_system_item::FileSystemItem, hex::is_binary_file, FileSystem},
+    state::{AppState, ChildProgramDesc, ModalType, PanelState, PickerMode, TabIdx, TabState},
 };
 
 use super::{reload_tab, reload_tab_contain_item, reload_tab_with_path};
@@ -18,7 +21,8 @@ pub fn file_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
         FileAction::Delete { panel } => delete_file(state, panel),
         FileAction::Rename { from, to } => rename_file(state, from, to),
         FileAction::Move { from, to } => rename_file(state, from, to),
-        FileAction::Open { panel } => open_file(state, panel),
+        FileAction::Open { panel } => open_file(state, panel, None),
+        FileAction::OpenAtLine { panel, line } => open_file(state, panel, Some(line)),
         FileAction::Create { file_name, panel } => create_file(state, file_name, panel),
         FileAction::Copy { from, to } => copy_file(state, from, to),
     }
@@ -137,9 +141,44 @@ fn create_file<TFileSystem: Clone + Debug + Default + FileSystem>(
 fn open_file<TFileSystem: Clone + Debug + Default + FileSystem>(
     state: AppState<TFileSystem>,
     panel: PanelInfo,
+    line: Option<usize>,
 ) -> AppState<TFileSystem> {
+    // Opening file in file picker chooses it instead of starting program
+    match state.picker_mode {
+        Some(PickerMode::Files) => {
+            return AppState {
+                chosen_paths: vec![panel.path],
+                app_exit: true,
+                ..state
+            }
+        }
+        Some(PickerMode::Directory) => return state,
+        None => {}
+    }
+
+    // Binary files are garbage in text editor, they go to hex viewer unless
+    // their extension has program configured
+    let file_extension = panel
+        .path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("");
+    if line.is_none()
+        && panel.path.is_file()
+        && !state
+            .config
+            .file_associated_programs
+            .has_program(file_extension)
+        && is_binary_file(&panel.path)
+    {
+        return AppState {
+            modal: Some(ModalType::HexViewerModal { path: panel.path }),
+            ..state
+        };
+    }
+
     AppState {
-        child_program: open_file_from_tab(panel.path, &state.config.file_associated_programs),
+        child_program: open_file_from_tab(panel.path, &state.config.file_associated_programs, line),
         ..state
     }
 }
@@ -262,13 +301,11 @@ fn create_file_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     let mut result = Vec::<TabState<TFileSystem>>::new();
     for (idx, tab_state) in tabs.iter_mut().enumerate() {
         if idx == tab {
-            if dir_path.exists() {
+            if file_system.get_dir_info(&dir_path).is_some() {
                 let mut file_path = dir_path.clone();
                 file_path.push(file_name.clone());
                 match file_system.create_file(&file_path) {
-                    Ok(_) => {
-                        result.push(TabState::with_dir(dir_path.as_path(), file_system, icons))
-                    }
+                    Ok(_) => result.push(tab_state.reload(file_system, icons)),
                     Err(_) => {}
                 }
             } else {
@@ -285,19 +322,41 @@ fn create_file_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
 fn open_file_from_tab(
     path: PathBuf,
     file_associated_programs: &FileAssociatedPrograms,
+    line: Option<usize>,
 ) -> Option<ChildProgramDesc> {
     if path.is_file() && path.exists() {
         let file_extension = path.extension().unwrap_or(OsStr::new(""));
+        let program_name = file_associated_programs
+            .get_program_name(String::from(file_extension.to_str().unwrap()));
+        let path = String::from(path.to_str().unwrap());
         Some(ChildProgramDesc {
-            program_name: file_associated_programs
-                .get_program_name(String::from(file_extension.to_str().unwrap())),
-            args: vec![String::from(path.to_str().unwrap())],
+            args: match line {
+                Some(line) => line_args(&program_name, path, line),
+                None => vec![path],
+            },
+            program_name,
         })
     } else {
         None
     }
 }
 
+// Every editor has its own way to jump to line, programs not known here just open file
+fn line_args(program_name: &str, path: String, line: usize) -> Vec<String> {
+    let program = Path::new(program_name)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program_name);
+    match program {
+        "vi" | "vim" | "nvim" | "nano" | "emacs" | "micro" | "kak" | "joe" | "mcedit" | "less" => {
+            vec![format!("+{}", line), path]
+        }
+        "code" | "codium" => vec!["-g".to_string(), format!("{}:{}", path, line)],
+        "hx" | "helix" | "subl" => vec![format!("{}:{}", path, line)],
+        _ => vec![path],
+    }
+}
+
 fn delete_file_from_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     path: PathBuf,
     current_tab: TabIdx,
@@ -316,11 +375,7 @@ fn delete_file_from_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
             if let Some(item) = item_to_delete {
                 if let FileSystemItem::File(file) = item {
                     match file_system.delete_file(&file.get_path()) {
-                        Ok(_) => result.push(TabState::with_dir(
-                            tab_state.path.as_path(),
-                            file_system,
-                            icons,
-                        )),
+                        Ok(_) => result.push(tab_state.reload(file_system, icons)),
                         Err(_) => {} //TODO: add error handling to state
                     }
                 } else {
@@ -349,11 +404,7 @@ fn copy_file_to_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     for (idx, tab_state) in tabs.iter_mut().enumerate() {
         if idx == current_tab {
             match file_system.copy_file(from.as_path(), to.as_path()) {
-                Ok(_) => result.push(TabState::with_dir(
-                    tab_state.path.as_path(),
-                    file_system,
-                    icons,
-                )),
+                Ok(_) => result.push(tab_state.reload(file_system, icons)),
                 Err(_) => {}
             }
         } else {
@@ -376,11 +427,7 @@ fn rename_file_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     for (idx, tab_state) in tabs.iter_mut().enumerate() {
         if idx == current_tab {
             match file_system.rename_item(&from.as_path(), &to.as_path()) {
-                Ok(_) => result.push(TabState::with_dir(
-                    tab_state.path.as_path(),
-                    file_system,
-                    icons,
-                )),
+                Ok(_) => result.push(tab_state.reload(file_system, icons)),
                 Err(_) => {} //TODO: add error handling to state
             }
         } else {
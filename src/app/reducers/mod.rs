@@ -1,14 +1,18 @@
 use super::{
     actions::{AppAction, FileManagerActions},
-    config::icon_cfg::IconsConfig,
-    file_system::FileSystem,
-    state::{AppState, PanelState, TabIdx, TabState},
+    config::{
+        icon_cfg::IconsConfig,
+        layout_cfg::{SplitDirection, MAX_RATIO, MIN_RATIO},
+    },
+    file_system::{sftp_file_system::RemotePath, FileSystem},
+    state::{AppState, PanelLayout, PanelState, TabIdx, TabState},
 };
 use std::{
     fmt::Debug,
     path::{Path, PathBuf},
 };
 
+mod bookmark_reducer;
 mod dir_reducer;
 mod file_reducer;
 mod panel_reducer;
@@ -16,6 +20,7 @@ mod search_reducer;
 mod symlink_reducer;
 mod tab_reducer;
 
+use bookmark_reducer::bookmark_reducer;
 use dir_reducer::dir_reducer;
 use file_reducer::file_reducer;
 use panel_reducer::panel_reducer;
@@ -37,6 +42,9 @@ pub fn root_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
         FileManagerActions::Panel(panel_action) => panel_reducer(state.clone(), panel_action),
         FileManagerActions::Tab(tab_action) => tab_reducer(state.clone(), tab_action),
         FileManagerActions::Search(search_action) => search_reducer(state.clone(), search_action),
+        FileManagerActions::Bookmark(bookmark_action) => {
+            bookmark_reducer(state.clone(), bookmark_action)
+        }
     }
 }
 
@@ -45,8 +53,21 @@ fn app_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
     app_action: AppAction,
 ) -> AppState<TFileSystem> {
     match app_action {
-        AppAction::Exit => AppState {
+        AppAction::Exit => {
+            let focused_path = state.focused_tab().path.clone();
+            AppState {
+                app_exit: true,
+                exit_path: if RemotePath::parse(&focused_path).is_none() {
+                    Some(focused_path)
+                } else {
+                    None
+                },
+                ..state
+            }
+        }
+        AppAction::ExitWithoutChangingDir => AppState {
             app_exit: true,
+            exit_path: None,
             ..state
         },
         AppAction::FocusLeft => AppState {
@@ -83,6 +104,54 @@ fn app_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
             modal: None,
             ..state
         },
+        AppAction::Choose(paths) => AppState {
+            chosen_paths: paths,
+            app_exit: true,
+            ..state
+        },
+        AppAction::TogglePreview => AppState {
+            preview_visible: !state.preview_visible,
+            ..state
+        },
+        AppAction::ToggleMillerColumns => AppState {
+            miller_columns: !state.miller_columns,
+            ..state
+        },
+        AppAction::ToggleZoom => AppState {
+            layout: PanelLayout {
+                zoomed: !state.layout.zoomed,
+                ..state.layout
+            },
+            ..state
+        },
+        AppAction::CycleSplitDirection => AppState {
+            layout: PanelLayout {
+                split: match state.layout.split {
+                    SplitDirection::Horizontal => SplitDirection::Vertical,
+                    SplitDirection::Vertical => SplitDirection::Auto,
+                    SplitDirection::Auto => SplitDirection::Horizontal,
+                },
+                ..state.layout
+            },
+            ..state
+        },
+        AppAction::ResizePanels(change) => {
+            // Ratio is size of left panel, growing right panel shrinks it
+            let change = if state.right_panel.is_focused {
+                -change
+            } else {
+                change
+            };
+            let ratio =
+                (state.layout.ratio as i16 + change).clamp(MIN_RATIO as i16, MAX_RATIO as i16);
+            AppState {
+                layout: PanelLayout {
+                    ratio: ratio as u16,
+                    ..state.layout
+                },
+                ..state
+            }
+        }
     }
 }
 
@@ -95,11 +164,7 @@ fn reload_tab<TFileSystem: Clone + Default + Debug + FileSystem>(
     let mut result = Vec::<TabState<TFileSystem>>::new();
     for (idx, tab_state) in tabs.iter().enumerate() {
         if idx == tab {
-            result.push(TabState::with_dir(
-                tab_state.path.as_path(),
-                file_system,
-                icons_cfg,
-            ));
+            result.push(tab_state.reload(file_system, icons_cfg));
         } else {
             result.push(tab_state.clone());
         }
@@ -117,11 +182,7 @@ fn reload_tab_with_path<TFileSystem: Clone + Default + Debug + FileSystem>(
     let mut result = Vec::<TabState<TFileSystem>>::new();
     for tab_state in tabs.iter() {
         if tab_state.path == tab_path {
-            result.push(TabState::with_dir(
-                tab_state.path.as_path(),
-                file_system,
-                icons_cfg,
-            ));
+            result.push(tab_state.reload(file_system, icons_cfg));
         } else {
             result.push(tab_state.clone());
         }
@@ -156,7 +217,7 @@ fn reload_if_contain<TFileSystem: Clone + Default + Debug + FileSystem>(
     icons_cfg: &IconsConfig,
 ) -> TabState<TFileSystem> {
     if tab_state.items.iter().any(|i| i.get_path() == path) {
-        TabState::with_dir(tab_state.path.as_path(), file_system, icons_cfg)
+        tab_state.reload(file_system, icons_cfg)
     } else {
         tab_state.clone()
     }
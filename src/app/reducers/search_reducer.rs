@@ -3,6 +3,7 @@ use tui::widgets::ListState;
 use crate::app::{
     actions::{PanelSide, SearchAction},
     file_system::FileSystem,
+    search_filter::{SearchFilter, SearchMode},
     state::{AppState, PanelState, TabIdx, TabState},
 };
 use std::fmt::Debug;
@@ -18,7 +19,8 @@ pub fn search_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
             tab,
             panel_side,
             phrase,
-        } => change_input(state.clone(), tab, panel_side, phrase),
+            mode,
+        } => change_input(state.clone(), tab, panel_side, phrase, mode),
         SearchAction::ApplySearch { tab, panel_side } => {
             apply_search(state.clone(), tab, panel_side)
         }
@@ -53,18 +55,19 @@ fn change_input<TFileSystem: Clone + Debug + Default + FileSystem>(
     tab: usize,
     panel_side: PanelSide,
     phrase: String,
+    mode: SearchMode,
 ) -> AppState<TFileSystem> {
     match panel_side {
         PanelSide::Left => AppState {
             left_panel: PanelState {
-                tabs: input_search_in_tab(state.left_panel.tabs, tab, phrase),
+                tabs: input_search_in_tab(state.left_panel.tabs, tab, phrase, mode),
                 ..state.left_panel
             },
             ..state
         },
         PanelSide::Right => AppState {
             right_panel: PanelState {
-                tabs: input_search_in_tab(state.right_panel.tabs, tab, phrase),
+                tabs: input_search_in_tab(state.right_panel.tabs, tab, phrase, mode),
                 ..state.right_panel
             },
             ..state
@@ -149,6 +152,7 @@ fn stop_search_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
             result.push(TabState {
                 search_mode: false,
                 phrase: String::from(""),
+                search_filter: SearchFilter::new(tab_state.search_filter.mode, ""),
                 ..tab_state.clone()
             });
         } else {
@@ -163,12 +167,14 @@ fn input_search_in_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     tabs: Vec<TabState<TFileSystem>>,
     tab: TabIdx,
     phrase: String,
+    mode: SearchMode,
 ) -> Vec<TabState<TFileSystem>> {
     let mut result = Vec::<TabState<TFileSystem>>::new();
 
     for (idx, tab_state) in tabs.iter().enumerate() {
         if idx == tab && tab_state.search_mode {
             result.push(TabState {
+                search_filter: SearchFilter::new(mode, &phrase),
                 phrase: phrase.clone(),
                 ..tab_state.clone()
             });
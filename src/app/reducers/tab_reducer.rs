@@ -1,7 +1,8 @@
 use crate::app::{
     actions::{PanelSide, TabAction},
+    config::icon_cfg::IconsConfig,
     file_system::FileSystem,
-    state::{AppState, PanelState, TabState},
+    state::{AppState, ModalType, PanelState, TabIdx, TabState},
 };
 use std::{fmt::Debug, path::PathBuf};
 
@@ -18,9 +19,216 @@ pub fn tab_reducer<TFileSystem: Clone + Debug + Default + FileSystem>(
         TabAction::SelectPrev => select_multiple_prev(state),
         TabAction::ClearSelection => clear_selections(state),
         TabAction::ReloadTab { panel_side, path } => reload_state_tab(state, panel_side, path),
+        TabAction::HistoryBack {
+            panel_side,
+            tab,
+            steps,
+        } => move_in_history(state, panel_side, tab, |tab_state, file_system, icons| {
+            tab_state.go_back(steps, file_system, icons)
+        }),
+        TabAction::HistoryForward {
+            panel_side,
+            tab,
+            steps,
+        } => move_in_history(state, panel_side, tab, |tab_state, file_system, icons| {
+            tab_state.go_forward(steps, file_system, icons)
+        }),
+        TabAction::SelectPath {
+            panel_side,
+            tab,
+            path,
+        } => select_path(state, panel_side, tab, path),
+        TabAction::ShowSearchResults {
+            panel_side,
+            root,
+            name,
+            paths,
+        } => show_search_results(state, panel_side, root, name, paths),
+        TabAction::ToggleExpanded {
+            panel_side,
+            tab,
+            path,
+        } => update_tab(state, panel_side, tab, |tab_state, file_system, icons| {
+            tab_state.toggle_expanded(&path, file_system, icons)
+        }),
+        TabAction::ToggleFlat { panel_side, tab } => {
+            let max_depth = state.config.flat.max_depth;
+            update_tab(state, panel_side, tab, |tab_state, file_system, icons| {
+                tab_state.toggle_flat(max_depth, file_system, icons)
+            })
+        }
+    }
+}
+
+fn show_search_results<TFileSystem: Clone + Debug + Default + FileSystem>(
+    state: AppState<TFileSystem>,
+    panel_side: PanelSide,
+    root: PathBuf,
+    name: String,
+    paths: Vec<PathBuf>,
+) -> AppState<TFileSystem> {
+    let mut results_tab =
+        TabState::with_search_results(&root, name, paths, &state.file_system, &state.config.icons);
+    results_tab.restore_selection(None, Some(0));
+
+    match panel_side {
+        PanelSide::Left => {
+            let mut tabs = state.left_panel.tabs.clone();
+            tabs.push(results_tab);
+            AppState {
+                left_panel: PanelState {
+                    current_tab: tabs.len() - 1,
+                    tabs,
+                    ..state.left_panel
+                },
+                ..state
+            }
+        }
+        PanelSide::Right => {
+            let mut tabs = state.right_panel.tabs.clone();
+            tabs.push(results_tab);
+            AppState {
+                right_panel: PanelState {
+                    current_tab: tabs.len() - 1,
+                    tabs,
+                    ..state.right_panel
+                },
+                ..state
+            }
+        }
+    }
+}
+
+fn select_path<TFileSystem: Clone + Debug + Default + FileSystem>(
+    state: AppState<TFileSystem>,
+    panel_side: PanelSide,
+    tab: TabIdx,
+    path: PathBuf,
+) -> AppState<TFileSystem> {
+    let tabs = match panel_side {
+        PanelSide::Left => &state.left_panel.tabs,
+        PanelSide::Right => &state.right_panel.tabs,
+    };
+    let mut selected_tab = match tabs.get(tab) {
+        Some(tab_state) => tab_state.clone(),
+        None => return state,
+    };
+    selected_tab.restore_selection(Some(path.as_path()), None);
+
+    match panel_side {
+        PanelSide::Left => AppState {
+            left_panel: PanelState {
+                tabs: replace_tab(tab, selected_tab, state.left_panel.tabs),
+                ..state.left_panel
+            },
+            ..state
+        },
+        PanelSide::Right => AppState {
+            right_panel: PanelState {
+                tabs: replace_tab(tab, selected_tab, state.right_panel.tabs),
+                ..state.right_panel
+            },
+            ..state
+        },
+    }
+}
+
+fn move_in_history<TFileSystem: Clone + Debug + Default + FileSystem, TMove>(
+    state: AppState<TFileSystem>,
+    panel_side: PanelSide,
+    tab: TabIdx,
+    move_tab: TMove,
+) -> AppState<TFileSystem>
+where
+    TMove: Fn(&TabState<TFileSystem>, &TFileSystem, &IconsConfig) -> Option<TabState<TFileSystem>>,
+{
+    let tabs = match panel_side {
+        PanelSide::Left => &state.left_panel.tabs,
+        PanelSide::Right => &state.right_panel.tabs,
+    };
+    let moved_tab = match tabs.get(tab) {
+        Some(tab_state) => move_tab(tab_state, &state.file_system, &state.config.icons),
+        None => return state,
+    };
+
+    match (moved_tab, panel_side) {
+        (Some(moved_tab), PanelSide::Left) => AppState {
+            left_panel: PanelState {
+                tabs: replace_tab(tab, moved_tab, state.left_panel.tabs),
+                ..state.left_panel
+            },
+            ..state
+        },
+        (Some(moved_tab), PanelSide::Right) => AppState {
+            right_panel: PanelState {
+                tabs: replace_tab(tab, moved_tab, state.right_panel.tabs),
+                ..state.right_panel
+            },
+            ..state
+        },
+        (None, _) => AppState {
+            modal: Some(ModalType::ErrorModal(
+                "Can't open directory from history".to_string(),
+            )),
+            ..state
+        },
+    }
+}
+
+// Replaces tab with its changed copy, eg. listing shown in other mode
+fn update_tab<TFileSystem: Clone + Debug + Default + FileSystem, TUpdate>(
+    state: AppState<TFileSystem>,
+    panel_side: PanelSide,
+    tab: TabIdx,
+    update: TUpdate,
+) -> AppState<TFileSystem>
+where
+    TUpdate: Fn(&TabState<TFileSystem>, &TFileSystem, &IconsConfig) -> TabState<TFileSystem>,
+{
+    let tabs = match panel_side {
+        PanelSide::Left => &state.left_panel.tabs,
+        PanelSide::Right => &state.right_panel.tabs,
+    };
+    let updated_tab = match tabs.get(tab) {
+        Some(tab_state) => update(tab_state, &state.file_system, &state.config.icons),
+        None => return state,
+    };
+
+    match panel_side {
+        PanelSide::Left => AppState {
+            left_panel: PanelState {
+                tabs: replace_tab(tab, updated_tab, state.left_panel.tabs),
+                ..state.left_panel
+            },
+            ..state
+        },
+        PanelSide::Right => AppState {
+            right_panel: PanelState {
+                tabs: replace_tab(tab, updated_tab, state.right_panel.tabs),
+                ..state.right_panel
+            },
+            ..state
+        },
     }
 }
 
+fn replace_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
+    tab: TabIdx,
+    new_tab: TabState<TFileSystem>,
+    tabs: Vec<TabState<TFileSystem>>,
+) -> Vec<TabState<TFileSystem>> {
+    let mut result = Vec::<TabState<TFileSystem>>::new();
+    for (idx, tab_state) in tabs.iter().enumerate() {
+        if idx == tab {
+            result.push(new_tab.clone());
+        } else {
+            result.push(tab_state.clone());
+        }
+    }
+
+    result
+}
+
 fn reload_state_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
     state: AppState<TFileSystem>,
     panel_side: PanelSide,
@@ -0,0 +1,195 @@
+use globset::{GlobBuilder, GlobMatcher};
+use regex::{Regex, RegexBuilder};
+
+use super::fuzzy::fuzzy_match;
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SearchMode {
+    #[default]
+    Plain,
+    Glob,
+    Regex,
+    Fuzzy,
+}
+
+impl SearchMode {
+    pub fn next(&self) -> Self {
+        match self {
+            SearchMode::Plain => SearchMode::Glob,
+            SearchMode::Glob => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::Fuzzy,
+            SearchMode::Fuzzy => SearchMode::Plain,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchMode::Plain => "plain",
+            SearchMode::Glob => "glob",
+            SearchMode::Regex => "regex",
+            SearchMode::Fuzzy => "fuzzy",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "plain" => Some(SearchMode::Plain),
+            "glob" => Some(SearchMode::Glob),
+            "regex" => Some(SearchMode::Regex),
+            "fuzzy" => Some(SearchMode::Fuzzy),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Matcher {
+    Plain(String),
+    Glob(GlobMatcher),
+    Regex(Regex),
+    Fuzzy(String),
+    Invalid,
+}
+
+// Phrase is compiled once per input so panel filtering does not rebuild
+// glob or regex on every redraw
+#[derive(Clone, Debug)]
+pub struct SearchFilter {
+    pub mode: SearchMode,
+    pub error: Option<String>,
+    matcher: Matcher,
+}
+
+impl Default for SearchFilter {
+    fn default() -> Self {
+        SearchFilter::new(SearchMode::default(), "")
+    }
+}
+
+impl SearchFilter {
+    pub fn new(mode: SearchMode, phrase: &str) -> Self {
+        // Glob and regex are case insensitive unless phrase contains upper case letter
+        let case_insensitive = !phrase.chars().any(|c| c.is_uppercase());
+        let (matcher, error) = match mode {
+            SearchMode::Plain => (Matcher::Plain(phrase.to_lowercase()), None),
+            SearchMode::Glob => match GlobBuilder::new(phrase)
+                .case_insensitive(case_insensitive)
+                .build()
+            {
+                Ok(glob) => (Matcher::Glob(glob.compile_matcher()), None),
+                Err(err) => (Matcher::Invalid, Some(err.kind().to_string())),
+            },
+            SearchMode::Regex => match RegexBuilder::new(phrase)
+                .case_insensitive(case_insensitive)
+                .build()
+            {
+                Ok(regex) => (Matcher::Regex(regex), None),
+                Err(err) => (Matcher::Invalid, Some(regex_error_message(&err))),
+            },
+            SearchMode::Fuzzy => (Matcher::Fuzzy(phrase.to_string()), None),
+        };
+
+        SearchFilter {
+            mode,
+            error,
+            matcher,
+        }
+    }
+
+    // Returns None when name does not match, otherwise rank used to order
+    // matches (only fuzzy mode ranks items, other modes keep directory order)
+    pub fn rank(&self, name: &str) -> Option<i64> {
+        match &self.matcher {
+            Matcher::Plain(phrase) => {
+                if name.to_lowercase().contains(phrase.as_str()) {
+                    Some(0)
+                } else {
+                    None
+                }
+            }
+            Matcher::Glob(glob) => {
+                if glob.is_match(name) {
+                    Some(0)
+                } else {
+                    None
+                }
+            }
+            Matcher::Regex(regex) => {
+                if regex.is_match(name) {
+                    Some(0)
+                } else {
+                    None
+                }
+            }
+            Matcher::Fuzzy(phrase) => fuzzy_match(phrase, name).map(|result| result.score),
+            Matcher::Invalid => None,
+        }
+    }
+}
+
+// Syntax errors are multi line with pattern and caret, last line has the reason
+pub fn regex_error_message(err: &regex::Error) -> String {
+    let message = err.to_string();
+    message
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("invalid pattern")
+        .trim_start_matches("error: ")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_filter_matches_substring_ignoring_case() {
+        let filter = SearchFilter::new(SearchMode::Plain, "Read");
+        assert_eq!(filter.rank("README.md"), Some(0));
+        assert_eq!(filter.rank("main.rs"), None);
+        assert!(filter.error.is_none());
+    }
+
+    #[test]
+    fn glob_filter_uses_smart_case() {
+        let filter = SearchFilter::new(SearchMode::Glob, "*.rs");
+        assert_eq!(filter.rank("main.rs"), Some(0));
+        assert_eq!(filter.rank("MAIN.RS"), Some(0));
+        assert_eq!(filter.rank("main.c"), None);
+
+        let filter = SearchFilter::new(SearchMode::Glob, "*.RS");
+        assert_eq!(filter.rank("main.rs"), None);
+    }
+
+    #[test]
+    fn regex_filter_matches_pattern() {
+        let filter = SearchFilter::new(SearchMode::Regex, "^ma.n\\.rs$");
+        assert_eq!(filter.rank("main.rs"), Some(0));
+        assert_eq!(filter.rank("domain.rs"), None);
+    }
+
+    #[test]
+    fn fuzzy_filter_ranks_by_score() {
+        let filter = SearchFilter::new(SearchMode::Fuzzy, "mn");
+        assert_eq!(
+            filter.rank("main.rs"),
+            fuzzy_match("mn", "main.rs").map(|result| result.score)
+        );
+        assert_eq!(filter.rank("lib.rs"), None);
+    }
+
+    #[test]
+    fn invalid_pattern_reports_error_and_matches_nothing() {
+        let filter = SearchFilter::new(SearchMode::Regex, "(main");
+        let error = filter.error.clone().unwrap();
+        assert!(!error.is_empty());
+        assert!(!error.starts_with("error: "));
+        assert!(!error.contains('\n'));
+        assert_eq!(filter.rank("(main"), None);
+
+        let filter = SearchFilter::new(SearchMode::Glob, "[main");
+        assert!(filter.error.is_some());
+        assert_eq!(filter.rank("[main"), None);
+    }
+}
@@ -0,0 +1,357 @@
+use std::{fmt::Debug, fs, io, path::PathBuf};
+
+use toml::{map::Map, Value};
+
+use super::{
+    actions::PanelSide,
+    config::icon_cfg::IconsConfig,
+    file_system::{sftp_file_system::RemotePath, FileSystem},
+    search_filter::{SearchFilter, SearchMode},
+    state::{AppState, PanelState, TabIdx, TabState},
+};
+
+pub const DEFAULT_SESSION_NAME: &str = "default";
+
+#[derive(Clone, Debug, Default)]
+pub struct TabSession {
+    pub path: PathBuf,
+    pub selected_index: Option<usize>,
+    pub filter: String,
+    pub filter_mode: SearchMode,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PanelSession {
+    pub tabs: Vec<TabSession>,
+    pub current_tab: TabIdx,
+}
+
+#[derive(Clone, Debug)]
+pub struct Session {
+    pub left_panel: PanelSession,
+    pub right_panel: PanelSession,
+    pub focused_panel: PanelSide,
+}
+
+impl Session {
+    pub fn from_state<TFileSystem: Clone + Debug + Default + FileSystem>(
+        state: &AppState<TFileSystem>,
+    ) -> Self {
+        Session {
+            left_panel: PanelSession::from_panel(&state.left_panel),
+            right_panel: PanelSession::from_panel(&state.right_panel),
+            focused_panel: if state.right_panel.is_focused {
+                PanelSide::Right
+            } else {
+                PanelSide::Left
+            },
+        }
+    }
+
+    pub fn load(name: &str) -> Option<Self> {
+        let content = fs::read_to_string(session_path(name).ok()?).ok()?;
+        Session::parse(&content)
+    }
+
+    pub fn save(&self, name: &str) -> io::Result<()> {
+        let path = session_path(name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_toml_string())
+    }
+
+    pub fn parse(content: &str) -> Option<Self> {
+        let session = content.parse::<Value>().ok()?;
+        let focused_panel = match session.get("focused").and_then(|focused| focused.as_str()) {
+            Some("right") => PanelSide::Right,
+            _ => PanelSide::Left,
+        };
+
+        Some(Session {
+            left_panel: PanelSession::parse(session.get("left")),
+            right_panel: PanelSession::parse(session.get("right")),
+            focused_panel,
+        })
+    }
+
+    pub fn to_toml_string(&self) -> String {
+        let mut session = Map::new();
+        session.insert(
+            "focused".to_string(),
+            Value::String(match self.focused_panel {
+                PanelSide::Left => "left".to_string(),
+                PanelSide::Right => "right".to_string(),
+            }),
+        );
+        session.insert("left".to_string(), self.left_panel.to_toml());
+        session.insert("right".to_string(), self.right_panel.to_toml());
+
+        Value::Table(session).to_string()
+    }
+
+    pub fn restore<TFileSystem: Clone + Debug + Default + FileSystem>(
+        &self,
+        state: &mut AppState<TFileSystem>,
+        restore_left: bool,
+        restore_right: bool,
+    ) {
+        if restore_left {
+            if let Some(panel) = self
+                .left_panel
+                .to_panel(&state.file_system, &state.config.icons)
+            {
+                state.left_panel = panel;
+            }
+        }
+        if restore_right {
+            if let Some(panel) = self
+                .right_panel
+                .to_panel(&state.file_system, &state.config.icons)
+            {
+                state.right_panel = panel;
+            }
+        }
+
+        state.left_panel.is_focused = self.focused_panel == PanelSide::Left;
+        state.right_panel.is_focused = self.focused_panel == PanelSide::Right;
+    }
+}
+
+impl PanelSession {
+    fn from_panel<TFileSystem: Clone + Debug + Default + FileSystem>(
+        panel: &PanelState<TFileSystem>,
+    ) -> Self {
+        let mut tabs = Vec::new();
+        let mut current_tab = 0;
+        for (idx, tab) in panel.tabs.iter().enumerate() {
+            // Remote tabs would connect on every start and search results are
+            // outdated, so neither is stored
+            if RemotePath::parse(&tab.path).is_some() || tab.search_results.is_some() {
+                continue;
+            }
+            if idx == panel.current_tab {
+                current_tab = tabs.len();
+            }
+            tabs.push(TabSession {
+                path: tab.path.clone(),
+                selected_index: tab.tab_state.selected(),
+                filter: tab.phrase.clone(),
+                filter_mode: tab.search_filter.mode,
+            });
+        }
+
+        PanelSession { tabs, current_tab }
+    }
+
+    fn parse(panel: Option<&Value>) -> Self {
+        let mut result = PanelSession::default();
+        if let Some(Value::Table(panel)) = panel {
+            if let Some(Value::Integer(current_tab)) = panel.get("current_tab") {
+                result.current_tab = *current_tab as usize;
+            }
+
+            if let Some(Value::Array(tabs)) = panel.get("tabs") {
+                for tab in tabs.iter() {
+                    if let Some(Value::String(path)) = tab.get("path") {
+                        result.tabs.push(TabSession {
+                            path: PathBuf::from(path),
+                            selected_index: match tab.get("selected") {
+                                Some(Value::Integer(selected)) => Some(*selected as usize),
+                                _ => None,
+                            },
+                            filter: match tab.get("filter") {
+                                Some(Value::String(filter)) => filter.clone(),
+                                _ => String::new(),
+                            },
+                            filter_mode: match tab.get("filter_mode") {
+                                Some(Value::String(filter_mode)) => {
+                                    SearchMode::from_name(filter_mode).unwrap_or_default()
+                                }
+                                _ => SearchMode::default(),
+                            },
+                        });
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn to_toml(&self) -> Value {
+        let mut tabs = Vec::new();
+        for tab in self.tabs.iter() {
+            let mut tab_table = Map::new();
+            tab_table.insert(
+                "path".to_string(),
+                Value::String(tab.path.to_str().unwrap_or("").to_string()),
+            );
+            if let Some(selected_index) = tab.selected_index {
+                tab_table.insert(
+                    "selected".to_string(),
+                    Value::Integer(selected_index as i64),
+                );
+            }
+            tab_table.insert("filter".to_string(), Value::String(tab.filter.clone()));
+            tab_table.insert(
+                "filter_mode".to_string(),
+                Value::String(tab.filter_mode.name().to_string()),
+            );
+            tabs.push(Value::Table(tab_table));
+        }
+
+        let mut panel = Map::new();
+        panel.insert(
+            "current_tab".to_string(),
+            Value::Integer(self.current_tab as i64),
+        );
+        panel.insert("tabs".to_string(), Value::Array(tabs));
+
+        Value::Table(panel)
+    }
+
+    fn to_panel<TFileSystem: Clone + Debug + Default + FileSystem>(
+        &self,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Option<PanelState<TFileSystem>> {
+        let mut tabs = Vec::new();
+        let mut current_tab = 0;
+        for (idx, tab) in self.tabs.iter().enumerate() {
+            // Directories removed since last run are skipped
+            if file_system.get_dir_info(&tab.path).is_none() {
+                continue;
+            }
+            if idx == self.current_tab {
+                current_tab = tabs.len();
+            }
+            tabs.push(tab.to_tab(file_system, icons));
+        }
+
+        if tabs.is_empty() {
+            None
+        } else {
+            Some(PanelState {
+                tabs,
+                current_tab,
+                ..PanelState::default()
+            })
+        }
+    }
+}
+
+impl TabSession {
+    fn to_tab<TFileSystem: Clone + Debug + Default + FileSystem>(
+        &self,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> TabState<TFileSystem> {
+        let mut tab = TabState::with_dir(self.path.as_path(), file_system, icons);
+        tab.phrase = self.filter.clone();
+        tab.search_filter = SearchFilter::new(self.filter_mode, &self.filter);
+        tab.restore_selection(None, self.selected_index);
+
+        tab
+    }
+}
+
+fn session_path(name: &str) -> io::Result<PathBuf> {
+    let file_name = session_file_name(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid session name '{}'", name),
+        )
+    })?;
+    let data_dir = dirs::data_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "can't find data directory"))?;
+
+    Ok(data_dir.join("sfm").join("sessions").join(file_name))
+}
+
+// Name is used as file name so it can't point outside of sessions directory,
+// dots inside name are kept (work.v2 is not work.toml)
+fn session_file_name(name: &str) -> Option<String> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', '\0']) {
+        None
+    } else {
+        Some(format!("{}.toml", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_file_name_keeps_dots() {
+        assert_eq!(
+            session_file_name("default"),
+            Some("default.toml".to_string())
+        );
+        assert_eq!(
+            session_file_name("work.v2"),
+            Some("work.v2.toml".to_string())
+        );
+    }
+
+    #[test]
+    fn session_file_name_rejects_paths() {
+        assert_eq!(session_file_name(""), None);
+        assert_eq!(session_file_name(".."), None);
+        assert_eq!(session_file_name(".hidden"), None);
+        assert_eq!(session_file_name("../../etc/passwd"), None);
+        assert_eq!(session_file_name("a/b"), None);
+        assert_eq!(session_file_name("a\\b"), None);
+    }
+
+    #[test]
+    fn session_round_trip() {
+        let session = Session {
+            left_panel: PanelSession {
+                tabs: vec![TabSession {
+                    path: PathBuf::from("/tmp"),
+                    selected_index: Some(3),
+                    filter: "rs".to_string(),
+                    filter_mode: SearchMode::Glob,
+                }],
+                current_tab: 0,
+            },
+            right_panel: PanelSession {
+                tabs: vec![
+                    TabSession {
+                        path: PathBuf::from("/home"),
+                        ..TabSession::default()
+                    },
+                    TabSession {
+                        path: PathBuf::from("/var"),
+                        ..TabSession::default()
+                    },
+                ],
+                current_tab: 1,
+            },
+            focused_panel: PanelSide::Right,
+        };
+
+        let parsed = Session::parse(&session.to_toml_string()).unwrap();
+        assert_eq!(parsed.focused_panel, PanelSide::Right);
+        assert_eq!(parsed.left_panel.tabs.len(), 1);
+        assert_eq!(parsed.left_panel.tabs[0].path, PathBuf::from("/tmp"));
+        assert_eq!(parsed.left_panel.tabs[0].selected_index, Some(3));
+        assert_eq!(parsed.left_panel.tabs[0].filter, "rs");
+        assert_eq!(parsed.left_panel.tabs[0].filter_mode, SearchMode::Glob);
+        assert_eq!(parsed.right_panel.tabs.len(), 2);
+        assert_eq!(parsed.right_panel.current_tab, 1);
+        assert_eq!(parsed.right_panel.tabs[0].selected_index, None);
+    }
+
+    #[test]
+    fn session_parse_ignores_missing_fields() {
+        let parsed = Session::parse("[left]\ntabs = [{ selected = 1 }]\n").unwrap();
+        assert_eq!(parsed.focused_panel, PanelSide::Left);
+        assert!(parsed.left_panel.tabs.is_empty());
+        assert!(parsed.right_panel.tabs.is_empty());
+        assert!(Session::parse("not toml [").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 
@@ -5,18 +6,60 @@ use tui::widgets::ListState;
 
 use super::{
     actions::PanelSide,
-    config::{icon_cfg::IconsConfig, Config},
-    file_system::{file_system_item::FileSystemItem, DirInfo, FileSystem},
+    bookmarks::Bookmarks,
+    config::{
+        icon_cfg::IconsConfig,
+        layout_cfg::{LayoutConfig, SplitDirection},
+        Config,
+    },
+    file_system::{file_system_item::FileSystemItem, FileSystem},
+    frecency::FrecencyDb,
+    search_filter::{SearchFilter, SearchMode},
 };
 
 pub type TabIdx = usize;
 
+const HISTORY_LIMIT: usize = 100;
+// Flat view of big subtree (eg. home directory) would be slow to list and render
+const MAX_FLAT_ITEMS: usize = 10000;
+
 #[derive(Clone, Debug)]
 pub struct ChildProgramDesc {
     pub program_name: String,
     pub args: Vec<String>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PickerMode {
+    Files,
+    Directory,
+}
+
+// Layout of panels which can be changed at runtime, starts as configured in [layout]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelLayout {
+    pub split: SplitDirection,
+    pub ratio: u16,
+    // Only focused panel is shown on whole screen
+    pub zoomed: bool,
+}
+
+impl PanelLayout {
+    pub fn from_config(layout: &LayoutConfig) -> Self {
+        PanelLayout {
+            split: layout.split,
+            ratio: layout.ratio,
+            zoomed: false,
+        }
+    }
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        PanelLayout::from_config(&LayoutConfig::default())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AppState<TFileSystem: Clone + Debug + Default + FileSystem> {
     pub left_panel: PanelState<TFileSystem>,
@@ -26,13 +69,43 @@ pub struct AppState<TFileSystem: Clone + Debug + Default + FileSystem> {
     pub child_program: Option<ChildProgramDesc>,
     pub modal: Option<ModalType>,
     pub file_system: TFileSystem,
+    pub picker_mode: Option<PickerMode>,
+    pub chosen_paths: Vec<PathBuf>,
+    pub exit_path: Option<PathBuf>,
+    pub bookmarks: Bookmarks,
+    pub frecency: FrecencyDb,
+    pub preview_visible: bool,
+    // Parent, current and preview columns are shown instead of two panels
+    pub miller_columns: bool,
+    pub layout: PanelLayout,
 }
 
 impl<TFileSystem: Clone + Debug + Default + FileSystem> AppState<TFileSystem> {
     pub fn new(config: Config, file_system: TFileSystem) -> Self {
-        let mut state = AppState::default();
-        state.file_system = file_system;
-        state.config = config;
+        AppState {
+            bookmarks: Bookmarks::load(
+                config.bookmarks_file_path(),
+                config.bookmarks.bookmarks.clone(),
+            ),
+            frecency: FrecencyDb::load(),
+            file_system,
+            layout: PanelLayout::from_config(&config.layout),
+            config,
+            ..AppState::default()
+        }
+    }
+
+    pub fn with_panels(
+        config: Config,
+        file_system: TFileSystem,
+        left_paths: Vec<PathBuf>,
+        right_paths: Vec<PathBuf>,
+    ) -> Self {
+        let mut state = AppState::new(config, file_system);
+        state.left_panel =
+            PanelState::with_paths(left_paths, &state.file_system, &state.config.icons);
+        state.right_panel =
+            PanelState::with_paths(right_paths, &state.file_system, &state.config.icons);
 
         state
     }
@@ -48,6 +121,14 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> Default for AppState<TFi
             child_program: None,
             modal: None,
             file_system: TFileSystem::default(),
+            picker_mode: None,
+            chosen_paths: Vec::new(),
+            exit_path: None,
+            bookmarks: Bookmarks::default(),
+            frecency: FrecencyDb::default(),
+            preview_visible: false,
+            miller_columns: false,
+            layout: PanelLayout::default(),
         }
     }
 }
@@ -60,6 +141,16 @@ pub struct PanelState<TFileSystem: Clone + Debug + Default + FileSystem> {
     pub marker: std::marker::PhantomData<TFileSystem>,
 }
 
+impl<TFileSystem: Clone + Debug + Default + FileSystem> AppState<TFileSystem> {
+    pub fn focused_tab(&self) -> &TabState<TFileSystem> {
+        if self.right_panel.is_focused {
+            &self.right_panel.tabs[self.right_panel.current_tab]
+        } else {
+            &self.left_panel.tabs[self.left_panel.current_tab]
+        }
+    }
+}
+
 impl<TFileSystem: Clone + Debug + Default + FileSystem> Default for PanelState<TFileSystem> {
     fn default() -> Self {
         PanelState {
@@ -71,6 +162,53 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> Default for PanelState<T
     }
 }
 
+impl<TFileSystem: Clone + Debug + Default + FileSystem> PanelState<TFileSystem> {
+    pub fn with_paths(paths: Vec<PathBuf>, file_system: &TFileSystem, icons: &IconsConfig) -> Self {
+        let paths = if paths.is_empty() {
+            vec![PathBuf::from(".")]
+        } else {
+            paths
+        };
+
+        PanelState {
+            tabs: paths
+                .iter()
+                .map(|path| TabState::with_dir(path.as_path(), file_system, icons))
+                .collect(),
+            ..PanelState::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HistoryEntry {
+    pub path: PathBuf,
+    pub selected_path: Option<PathBuf>,
+    pub selected_index: Option<usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TabHistory {
+    pub back: Vec<HistoryEntry>,
+    pub forward: Vec<HistoryEntry>,
+    // Last cursor position in recently visited directories, least recent first
+    pub cursors: Vec<HistoryEntry>,
+}
+
+impl TabHistory {
+    pub fn cursor(&self, path: &Path) -> Option<&HistoryEntry> {
+        self.cursors.iter().find(|cursor| cursor.path == path)
+    }
+
+    fn remember_cursor(&mut self, entry: HistoryEntry) {
+        self.cursors.retain(|cursor| cursor.path != entry.path);
+        self.cursors.push(entry);
+        if self.cursors.len() > HISTORY_LIMIT {
+            self.cursors.remove(0);
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TabState<TFileSystem: Clone + Debug + Default + FileSystem> {
     pub name: String,
@@ -81,6 +219,14 @@ pub struct TabState<TFileSystem: Clone + Debug + Default + FileSystem> {
     pub tab_state: ListState,
     pub search_mode: bool,
     pub phrase: String,
+    pub search_filter: SearchFilter,
+    // Paths listed by virtual search results tab instead of directory content
+    pub search_results: Option<Vec<PathBuf>>,
+    // Directories expanded in place, their content is listed below them as tree
+    pub expanded: Vec<PathBuf>,
+    // Depth of subtree listed as one flat list of files, None when directory is listed as usual
+    pub flat_depth: Option<usize>,
+    pub history: TabHistory,
     pub marker: std::marker::PhantomData<TFileSystem>,
 }
 
@@ -96,7 +242,7 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> Default for TabState<TFi
 
 impl<TFileSystem: Clone + Debug + Default + FileSystem> TabState<TFileSystem> {
     pub fn with_dir(dir_path: &Path, file_system: &TFileSystem, icons: &IconsConfig) -> Self {
-        let dir_info = DirInfo::new(&dir_path).unwrap();
+        let dir_info = file_system.get_dir_info(dir_path).unwrap();
         let items = file_system.list_dir(&dir_info.path, icons);
         TabState {
             name: dir_info.name.clone(),
@@ -107,24 +253,359 @@ impl<TFileSystem: Clone + Debug + Default + FileSystem> TabState<TFileSystem> {
             tab_state: ListState::default(),
             search_mode: false,
             phrase: String::from(""),
+            search_filter: SearchFilter::default(),
+            search_results: None,
+            expanded: Vec::new(),
+            flat_depth: None,
+            history: TabHistory::default(),
             marker: std::marker::PhantomData,
         }
     }
 
+    pub fn with_search_results(
+        root: &Path,
+        name: String,
+        paths: Vec<PathBuf>,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Self {
+        TabState {
+            icon: icons.get_dir_icon(name.clone()),
+            name,
+            items: load_search_results(&paths, file_system, icons),
+            search_results: Some(paths),
+            ..TabState::with_dir(root, file_system, icons)
+        }
+    }
+
+    pub fn reload(&self, file_system: &TFileSystem, icons: &IconsConfig) -> Self {
+        let entry = self.history_entry();
+        let mut tab = match (&self.search_results, self.flat_depth) {
+            // Removed results are dropped, new files are not searched for again
+            (Some(paths), _) => {
+                let items = load_search_results(paths, file_system, icons);
+                TabState {
+                    search_results: Some(items.iter().map(|item| item.get_path()).collect()),
+                    items,
+                    selected: Vec::new(),
+                    tab_state: ListState::default(),
+                    ..self.clone()
+                }
+            }
+            (None, Some(depth)) => TabState {
+                history: self.history.clone(),
+                items: load_flat(&self.path, depth, file_system, icons),
+                flat_depth: Some(depth),
+                ..TabState::with_dir(self.path.as_path(), file_system, icons)
+            },
+            (None, None) if !self.expanded.is_empty() => TabState {
+                history: self.history.clone(),
+                items: load_tree(&self.path, &self.expanded, file_system, icons),
+                expanded: self.expanded.clone(),
+                ..TabState::with_dir(self.path.as_path(), file_system, icons)
+            },
+            (None, None) => TabState {
+                history: self.history.clone(),
+                ..TabState::with_dir(self.path.as_path(), file_system, icons)
+            },
+        };
+        tab.restore_selection(entry.selected_path.as_deref(), entry.selected_index);
+
+        tab
+    }
+
+    pub fn navigate_to(&self, path: &Path, file_system: &TFileSystem, icons: &IconsConfig) -> Self {
+        let mut history = self.history_with_cursor();
+        history.back.push(self.history_entry());
+        if history.back.len() > HISTORY_LIMIT {
+            history.back.remove(0);
+        }
+        history.forward.clear();
+
+        let mut tab = TabState {
+            history,
+            ..TabState::with_dir(path, file_system, icons)
+        };
+        // Going up lands on directory we just left, otherwise last cursor in that directory is used
+        if self.path.parent() == Some(tab.path.as_path()) {
+            tab.restore_selection(Some(self.path.as_path()), None);
+        } else if let Some(cursor) = tab.history.cursor(&tab.path).cloned() {
+            tab.restore_selection(cursor.selected_path.as_deref(), cursor.selected_index);
+        }
+
+        tab
+    }
+
+    pub fn go_back(
+        &self,
+        steps: usize,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Option<Self> {
+        if steps == 0 || steps > self.history.back.len() {
+            return None;
+        }
+
+        let mut history = self.history_with_cursor();
+        let mut entry = self.history_entry();
+        for _ in 0..steps {
+            history.forward.push(entry);
+            entry = history.back.pop().unwrap();
+        }
+
+        TabState::from_history_entry(entry, history, file_system, icons)
+    }
+
+    pub fn go_forward(
+        &self,
+        steps: usize,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Option<Self> {
+        if steps == 0 || steps > self.history.forward.len() {
+            return None;
+        }
+
+        let mut history = self.history_with_cursor();
+        let mut entry = self.history_entry();
+        for _ in 0..steps {
+            history.back.push(entry);
+            entry = history.forward.pop().unwrap();
+        }
+
+        TabState::from_history_entry(entry, history, file_system, icons)
+    }
+
+    pub fn history_entry(&self) -> HistoryEntry {
+        let selected_index = self.tab_state.selected();
+        HistoryEntry {
+            path: self.path.clone(),
+            selected_path: selected_index
+                .and_then(|idx| self.filtered_items().get(idx).map(|item| item.get_path())),
+            selected_index,
+        }
+    }
+
+    pub fn restore_selection(
+        &mut self,
+        selected_path: Option<&Path>,
+        selected_index: Option<usize>,
+    ) {
+        let filtered_items = self.filtered_items();
+        let restored_index = selected_path
+            .and_then(|selected_path| {
+                filtered_items
+                    .iter()
+                    .position(|item| item.get_path() == selected_path)
+            })
+            .or(selected_index
+                .filter(|_| !filtered_items.is_empty())
+                .map(|idx| idx.min(filtered_items.len() - 1)));
+
+        if let Some(restored_index) = restored_index {
+            let selected_item = filtered_items[restored_index].clone();
+            self.tab_state.select(Some(restored_index));
+            self.selected = vec![selected_item];
+        }
+    }
+
+    fn history_with_cursor(&self) -> TabHistory {
+        let mut history = self.history.clone();
+        history.remember_cursor(self.history_entry());
+
+        history
+    }
+
+    fn from_history_entry(
+        entry: HistoryEntry,
+        history: TabHistory,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Option<Self> {
+        file_system.get_dir_info(&entry.path)?;
+
+        let mut tab = TabState {
+            history,
+            ..TabState::with_dir(entry.path.as_path(), file_system, icons)
+        };
+        tab.restore_selection(entry.selected_path.as_deref(), entry.selected_index);
+
+        Some(tab)
+    }
+
+    // Collapsing directory collapses also directories expanded inside of it
+    pub fn toggle_expanded(
+        &self,
+        dir_path: &Path,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Self {
+        let mut expanded = self.expanded.clone();
+        if expanded.iter().any(|path| path == dir_path) {
+            expanded.retain(|path| !path.starts_with(dir_path));
+        } else {
+            expanded.push(dir_path.to_path_buf());
+        }
+
+        let mut tab = TabState {
+            items: load_tree(&self.path, &expanded, file_system, icons),
+            expanded,
+            ..self.clone()
+        };
+        tab.restore_selection(Some(dir_path), self.tab_state.selected());
+
+        tab
+    }
+
+    // Leaving flat view moves cursor to top level item which contains file under cursor
+    pub fn toggle_flat(
+        &self,
+        max_depth: usize,
+        file_system: &TFileSystem,
+        icons: &IconsConfig,
+    ) -> Self {
+        let current_path = self.current_item().map(|item| item.get_path());
+        match self.flat_depth {
+            Some(_) => {
+                let mut tab = TabState {
+                    history: self.history.clone(),
+                    ..TabState::with_dir(self.path.as_path(), file_system, icons)
+                };
+                let top_level_path = current_path.as_ref().and_then(|path| {
+                    path.strip_prefix(&self.path)
+                        .ok()
+                        .and_then(|relative| relative.components().next())
+                        .map(|component| self.path.join(component))
+                });
+                tab.restore_selection(top_level_path.as_deref(), Some(0));
+
+                tab
+            }
+            None => {
+                let mut tab = TabState {
+                    items: load_flat(&self.path, max_depth, file_system, icons),
+                    selected: Vec::new(),
+                    tab_state: ListState::default(),
+                    search_mode: false,
+                    phrase: String::from(""),
+                    expanded: Vec::new(),
+                    flat_depth: Some(max_depth),
+                    ..self.clone()
+                };
+                tab.restore_selection(current_path.as_deref(), Some(0));
+
+                tab
+            }
+        }
+    }
+
+    // Nesting level of item in tree, items of tab directory have level 0
+    pub fn depth(&self, item: &FileSystemItem) -> usize {
+        item.get_path()
+            .strip_prefix(&self.path)
+            .map(|relative| relative.components().count().saturating_sub(1))
+            .unwrap_or(0)
+    }
+
+    pub fn current_item(&self) -> Option<&FileSystemItem> {
+        self.tab_state
+            .selected()
+            .and_then(|idx| self.filtered_items().get(idx).copied())
+    }
+
     pub fn filtered_items(&self) -> Vec<&FileSystemItem> {
         if self.phrase.is_empty() {
             self.items.iter().collect()
         } else {
-            self.items
+            let mut ranked: Vec<(i64, &FileSystemItem)> = self
+                .items
                 .iter()
-                .filter(|item| {
-                    item.get_name()
-                        .to_lowercase()
-                        .contains(&self.phrase.to_lowercase())
+                .filter_map(|item| {
+                    self.search_filter
+                        .rank(item.get_name().as_str())
+                        .map(|rank| (rank, item))
                 })
-                .collect()
+                .collect();
+            if self.search_filter.mode == SearchMode::Fuzzy {
+                ranked.sort_by_key(|(rank, _)| std::cmp::Reverse(*rank));
+            }
+
+            ranked.into_iter().map(|(_, item)| item).collect()
+        }
+    }
+}
+
+// Items are taken from listing of their parent directories so every file
+// system can be used, each parent is listed once
+fn load_search_results<TFileSystem: FileSystem>(
+    paths: &[PathBuf],
+    file_system: &TFileSystem,
+    icons: &IconsConfig,
+) -> Vec<FileSystemItem> {
+    let mut listings: HashMap<PathBuf, Vec<FileSystemItem>> = HashMap::new();
+    let mut result = Vec::new();
+    for path in paths.iter() {
+        let parent = match path.parent() {
+            Some(parent) => parent.to_path_buf(),
+            None => continue,
+        };
+        let listing = listings
+            .entry(parent.clone())
+            .or_insert_with(|| file_system.list_dir(&parent, icons));
+        if let Some(item) = listing.iter().find(|item| item.get_path() == *path) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
+// Files of directory come before files of its subdirectories, symlinks are not followed
+fn load_flat<TFileSystem: FileSystem>(
+    dir_path: &Path,
+    depth: usize,
+    file_system: &TFileSystem,
+    icons: &IconsConfig,
+) -> Vec<FileSystemItem> {
+    let mut result = Vec::new();
+    let mut dirs = Vec::new();
+    for item in file_system.list_dir(dir_path, icons) {
+        if item.is_dir() {
+            dirs.push(item.get_path());
+        } else {
+            result.push(item);
         }
     }
+    if depth > 1 {
+        for dir in dirs {
+            if result.len() >= MAX_FLAT_ITEMS {
+                break;
+            }
+            result.extend(load_flat(&dir, depth - 1, file_system, icons));
+        }
+    }
+    result.truncate(MAX_FLAT_ITEMS);
+
+    result
+}
+
+fn load_tree<TFileSystem: FileSystem>(
+    dir_path: &Path,
+    expanded: &[PathBuf],
+    file_system: &TFileSystem,
+    icons: &IconsConfig,
+) -> Vec<FileSystemItem> {
+    let mut result = Vec::new();
+    for item in file_system.list_dir(dir_path, icons) {
+        let item_path = item.get_path();
+        let is_expanded = item.is_dir() && expanded.contains(&item_path);
+        result.push(item);
+        if is_expanded {
+            result.extend(load_tree(&item_path, expanded, file_system, icons));
+        }
+    }
+
+    result
 }
 
 #[derive(Clone, Debug)]
@@ -146,4 +627,45 @@ pub enum ModalType {
         panel_tab: TabIdx,
         path: PathBuf,
     },
+    ConnectModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    BookmarksModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    HistoryModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    JumpModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    GoToModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    FinderModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    FindModal {
+        panel_side: PanelSide,
+    },
+    GrepModal {
+        panel_side: PanelSide,
+        panel_tab: TabIdx,
+    },
+    HexViewerModal {
+        path: PathBuf,
+    },
+    ViewerModal {
+        path: PathBuf,
+    },
+    DiffModal {
+        old_path: PathBuf,
+        new_path: PathBuf,
+    },
 }
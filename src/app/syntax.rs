@@ -0,0 +1,154 @@
+use std::{ops::Range, path::Path};
+
+use syntect::parsing::{
+    ParseState, Scope, ScopeStack, SyntaxDefinition, SyntaxReference, SyntaxSet,
+};
+
+// Minified files have huge lines which are slow to parse and not worth coloring
+const MAX_LINE_LENGTH: usize = 1000;
+
+lazy_static! {
+    static ref SYNTAX_SET: SyntaxSet = load_syntax_set();
+    static ref SCOPE_KINDS: Vec<(Scope, TokenKind)> = [
+        ("comment", TokenKind::Comment),
+        ("string", TokenKind::String),
+        ("markup.raw", TokenKind::String),
+        ("constant.numeric", TokenKind::Number),
+        ("constant", TokenKind::Constant),
+        ("entity.name.function", TokenKind::Function),
+        ("support.function", TokenKind::Function),
+        ("variable.function", TokenKind::Function),
+        ("entity.name.section", TokenKind::Heading),
+        ("markup.heading", TokenKind::Heading),
+        ("entity.name.tag", TokenKind::Keyword),
+        ("entity.name", TokenKind::Type),
+        ("support.type.property-name", TokenKind::Key),
+        ("variable.other.key", TokenKind::Key),
+        ("meta.mapping.key", TokenKind::Key),
+        ("storage.type", TokenKind::Type),
+        ("support.type", TokenKind::Type),
+        ("support.class", TokenKind::Type),
+        ("keyword", TokenKind::Keyword),
+        ("storage", TokenKind::Keyword),
+        ("variable.parameter", TokenKind::Variable),
+        ("variable.language", TokenKind::Keyword),
+        ("markup.bold", TokenKind::Keyword),
+        ("markup.italic", TokenKind::Keyword),
+        ("markup.underline.link", TokenKind::Function),
+    ]
+    .iter()
+    .map(|(scope, kind)| (Scope::new(scope).unwrap(), *kind))
+    .collect();
+}
+
+// Kinds of tokens are mapped to colors of configured color scheme, so
+// preview looks the same as rest of interface instead of using editor theme
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenKind {
+    Plain,
+    Comment,
+    String,
+    Number,
+    Constant,
+    Keyword,
+    Function,
+    Type,
+    Heading,
+    Key,
+    Variable,
+}
+
+// Byte ranges of line with their token kind
+pub type LineHighlight = Vec<(TokenKind, Range<usize>)>;
+
+fn load_syntax_set() -> SyntaxSet {
+    let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+    if let Ok(toml) = SyntaxDefinition::load_from_str(
+        include_str!("../../assets/syntaxes/TOML.sublime-syntax"),
+        true,
+        None,
+    ) {
+        builder.add(toml);
+    }
+    builder.build()
+}
+
+fn find_syntax(path: &Path, first_line: &str) -> Option<&'static SyntaxReference> {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("");
+    SYNTAX_SET
+        .find_syntax_by_extension(file_name)
+        .or_else(|| SYNTAX_SET.find_syntax_by_extension(extension))
+        .or_else(|| SYNTAX_SET.find_syntax_by_first_line(first_line))
+        .filter(|syntax| syntax.name != "Plain Text")
+}
+
+// Returns None when file type is unknown or content is not worth highlighting
+pub fn highlight(path: &Path, lines: &[String]) -> Option<Vec<LineHighlight>> {
+    if lines.iter().any(|line| line.len() > MAX_LINE_LENGTH) {
+        return None;
+    }
+
+    let syntax = find_syntax(path, lines.first().map(|line| line.as_str()).unwrap_or(""))?;
+    let mut parse_state = ParseState::new(syntax);
+    let mut scope_stack = ScopeStack::new();
+    let mut result = Vec::with_capacity(lines.len());
+    for line in lines.iter() {
+        // Default syntaxes expect lines with new line character
+        let line_with_newline = format!("{}\n", line);
+        let operations = parse_state
+            .parse_line(&line_with_newline, &SYNTAX_SET)
+            .ok()?;
+
+        let mut line_highlight = LineHighlight::new();
+        let mut position = 0;
+        for (idx, operation) in operations.iter() {
+            let idx = (*idx).min(line.len());
+            if idx > position {
+                push_token(&mut line_highlight, token_kind(&scope_stack), position..idx);
+                position = idx;
+            }
+            scope_stack.apply(operation).ok()?;
+        }
+        if position < line.len() {
+            push_token(
+                &mut line_highlight,
+                token_kind(&scope_stack),
+                position..line.len(),
+            );
+        }
+        result.push(line_highlight);
+    }
+
+    Some(result)
+}
+
+// Innermost scope with known kind decides, eg. escape inside of string is constant
+fn token_kind(scope_stack: &ScopeStack) -> TokenKind {
+    for scope in scope_stack.as_slice().iter().rev() {
+        for (prefix, kind) in SCOPE_KINDS.iter() {
+            if prefix.is_prefix_of(*scope) {
+                return *kind;
+            }
+        }
+    }
+
+    TokenKind::Plain
+}
+
+fn push_token(line_highlight: &mut LineHighlight, kind: TokenKind, range: Range<usize>) {
+    if let Some((last_kind, last_range)) = line_highlight.last_mut() {
+        if *last_kind == kind && last_range.end == range.start {
+            last_range.end = range.end;
+            return;
+        }
+    }
+
+    line_highlight.push((kind, range));
+}
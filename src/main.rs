@@ -4,15 +4,27 @@ extern crate lazy_static;
 use crate::core::events::Event;
 use crate::core::ui::component::Component;
 use crate::core::{events::EventQueue, store::Store};
-use std::{error::Error, io::stdout, process::Command};
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{stdout, Write},
+    path::PathBuf,
+    process::Command,
+};
 
 use app::{
     actions::FileManagerActions,
+    command_line::{CommandLineArgs, USAGE},
     components::root::RootComponent,
     config::Config,
-    file_system::PhysicalFileSystem,
-    middlewares::{dir_middleware, symlink_middleware},
+    file_system::{
+        functions::expand_if_contains_tilde, read_only_file_system::ReadOnlyFileSystem,
+        CompositeFileSystem, FileSystem,
+    },
+    frecency::FrecencyDb,
+    middlewares::{dir_middleware, file_middleware, read_only_middleware, symlink_middleware},
     reducers::root_reducer,
+    session::{Session, DEFAULT_SESSION_NAME},
     state::AppState,
 };
 
@@ -33,27 +45,115 @@ pub mod app;
 pub mod core;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let file_system = PhysicalFileSystem::default();
-    let cfg = Config::load_or_default(CONFIG_PATHS.to_vec(), &file_system);
+    let args = match CommandLineArgs::parse(std::env::args()) {
+        Ok(args) => args,
+        Err(err) => exit_with_error(format!("{}\n\n{}", err, USAGE)),
+    };
+
+    if args.show_help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    if args.show_version {
+        println!("sfm {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    if args.import_z.is_some() || args.import_zoxide {
+        let mut frecency = FrecencyDb::load();
+        let imported = match args.import_z {
+            Some(ref z_file) => {
+                frecency.import_z(expand_if_contains_tilde(z_file).unwrap_or(z_file.clone()))
+            }
+            None => frecency.import_zoxide(),
+        };
+        match imported {
+            Ok(count) => println!("sfm: imported {} directories", count),
+            Err(err) => exit_with_error(format!("can't import directories: {}", err)),
+        }
+        return Ok(());
+    }
+
+    let file_system = CompositeFileSystem::default();
+    let config_paths = match args.config_path {
+        Some(config_path) => {
+            let config_path = expand_if_contains_tilde(&config_path).unwrap_or(config_path);
+            if file_system.read_to_string(&config_path).is_err() {
+                exit_with_error(format!(
+                    "can't read config file '{}'",
+                    config_path.to_str().unwrap_or("")
+                ));
+            }
+            vec![config_path.to_str().unwrap_or("").to_string()]
+        }
+        None => CONFIG_PATHS.to_vec(),
+    };
+    let mut cfg = Config::load_or_default(config_paths, &file_system);
+    if args.read_only {
+        cfg.read_only.enabled = true;
+    }
+    if args.no_icons {
+        cfg.icons.use_icons = false;
+    }
+
+    let restore_left = args.left_paths.is_empty();
+    let restore_right = args.right_paths.is_empty();
+    let session_name = args
+        .session_name
+        .clone()
+        .unwrap_or(DEFAULT_SESSION_NAME.to_string());
+    let left_paths = resolve_start_paths(args.left_paths, &file_system);
+    let right_paths = resolve_start_paths(args.right_paths, &file_system);
+    let file_system = ReadOnlyFileSystem::new(file_system, cfg.read_only.clone());
+    let picker_output = args.picker.as_ref().map(|(_, output)| output.clone());
+    // Picker starts where it was called from and must not overwrite session of normal use
+    let use_session = !args.no_session && args.picker.is_none();
     enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // When chosen paths go to stdout the interface has to be drawn directly on terminal
+    let mut output: Box<dyn Write> = match picker_output {
+        Some(ref output) if output.to_str() == Some("-") => {
+            Box::new(OpenOptions::new().write(true).open("/dev/tty")?)
+        }
+        _ => Box::new(stdout()),
+    };
+    execute!(output, EnterAlternateScreen, EnableMouseCapture)?;
 
-    let backend = CrosstermBackend::new(stdout);
+    let backend = CrosstermBackend::new(output);
 
     let mut terminal = Terminal::new(backend)?;
     let mut event_queue = EventQueue::start_with_config(cfg.core_cfg.clone());
 
-    let mut store = Store::<AppState<PhysicalFileSystem>, FileManagerActions>::with_state(
-        root_reducer,
-        AppState::<PhysicalFileSystem>::new(cfg, file_system),
+    let mut app_state = AppState::<ReadOnlyFileSystem<CompositeFileSystem>>::with_panels(
+        cfg,
+        file_system,
+        left_paths,
+        right_paths,
     );
+    app_state.picker_mode = args.picker.map(|(picker_mode, _)| picker_mode);
+    if use_session {
+        if let Some(session) = Session::load(&session_name) {
+            session.restore(&mut app_state, restore_left, restore_right);
+        }
+    }
+    let focus_action = if app_state.right_panel.is_focused {
+        app::actions::AppAction::FocusRight
+    } else {
+        app::actions::AppAction::FocusLeft
+    };
+    let mut store =
+        Store::<AppState<ReadOnlyFileSystem<CompositeFileSystem>>, FileManagerActions>::with_state(
+            root_reducer,
+            app_state,
+        );
 
     terminal.clear()?;
 
     let mut root_component = RootComponent::new();
-    store.dispatch(FileManagerActions::App(app::actions::AppAction::FocusLeft));
+    store.dispatch(FileManagerActions::App(focus_action));
+    store.register_middleware(read_only_middleware);
     store.register_middleware(symlink_middleware);
+    store.register_middleware(file_middleware);
     store.register_middleware(dir_middleware);
     root_component.on_init(&store);
 
@@ -70,22 +170,19 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
         }
 
-        if let Some(program_desc) = state.child_program {
+        if let Some(ref program_desc) = state.child_program {
             event_queue.lock_event_read();
-            match Command::new(program_desc.program_name)
+            if let Ok(mut child) = Command::new(&program_desc.program_name)
                 .args(program_desc.args.as_slice())
                 .spawn()
             {
-                Ok(mut child) => {
-                    child.wait().expect("");
-                    store.dispatch(FileManagerActions::App(
-                        app::actions::AppAction::ChildProgramClosed,
-                    ));
-                    terminal.clear()?;
-                    terminal.draw(|f| root_component.render(f, None))?;
-                    event_queue.unlock_event_read();
-                }
-                Err(_) => {}
+                child.wait().expect("");
+                store.dispatch(FileManagerActions::App(
+                    app::actions::AppAction::ChildProgramClosed,
+                ));
+                terminal.clear()?;
+                terminal.draw(|f| root_component.render(f, None))?;
+                event_queue.unlock_event_read();
             };
         }
 
@@ -98,9 +195,67 @@ fn main() -> Result<(), Box<dyn Error>> {
                 DisableMouseCapture
             )?;
             terminal.show_cursor()?;
+            if let Some(ref picker_output) = picker_output {
+                write_chosen_paths(picker_output, &state.chosen_paths, args.print0)?;
+            }
+            if let Err(err) = state.frecency.save() {
+                eprintln!("sfm: can't save frecency database: {}", err);
+            }
+            if use_session {
+                if let Err(err) = Session::from_state(&state).save(&session_name) {
+                    eprintln!("sfm: can't save session '{}': {}", session_name, err);
+                }
+            }
+            if let (Some(ref cwd_file), Some(ref exit_path)) = (&args.cwd_file, &state.exit_path) {
+                fs::write(cwd_file, exit_path.to_str().unwrap_or(""))?;
+            }
             break;
         }
     }
 
     Ok(())
 }
+
+fn write_chosen_paths(
+    output: &PathBuf,
+    chosen_paths: &[PathBuf],
+    print0: bool,
+) -> Result<(), Box<dyn Error>> {
+    let separator = if print0 { "\0" } else { "\n" };
+    let mut content = String::new();
+    for path in chosen_paths.iter() {
+        content.push_str(path.to_str().unwrap_or(""));
+        content.push_str(separator);
+    }
+
+    if output.to_str() == Some("-") {
+        let mut stdout = stdout();
+        stdout.write_all(content.as_bytes())?;
+        stdout.flush()?;
+    } else {
+        fs::write(output, content)?;
+    }
+
+    Ok(())
+}
+
+fn resolve_start_paths(paths: Vec<PathBuf>, file_system: &CompositeFileSystem) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    for path in paths {
+        let expanded_path = expand_if_contains_tilde(&path).unwrap_or(path);
+        match file_system.get_dir_info(&expanded_path) {
+            Some(dir_info) => result.push(dir_info.path),
+            None => exit_with_error(format!(
+                "can't open directory '{}'",
+                expanded_path.to_str().unwrap_or("")
+            )),
+        }
+    }
+
+    result
+}
+
+fn exit_with_error(message: String) -> ! {
+    eprintln!("sfm: {}", message);
+    std::process::exit(2)
+}